@@ -0,0 +1,27 @@
+use languages_rs::*;
+
+fn main() -> anyhow::Result<()> {
+    // Create a new configuration
+    let config = Config::new("examples/texts/namespaces", vec!["en", "es"])?;
+
+    // The texts are loaded when they are used.
+    let mut texts = Languages::new(&config);
+
+    // This only loads `examples/texts/namespaces/en/checkout.json`.
+    if let Some(title_en) = texts.try_get_text_from_language("en", "checkout:title")? {
+        println!("Checkout title (English): {}", title_en);
+    }
+
+    // This loads all the namespaces of the Spanish texts.
+    let texts_es = texts.try_get_language("es")?;
+
+    if let Some(greeting_es) = texts_es.try_get_text("common:greeting") {
+        println!("Greeting (Spanish): {}", greeting_es);
+    }
+
+    if let Some(pay_es) = texts_es.try_get_text("checkout:pay") {
+        println!("Pay button (Spanish): {}", pay_es);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,256 @@
+use std::{fs, path::Path};
+
+use serde_json::{Map, Value as JsonValue};
+
+use crate::{LanguageTexts, Value};
+
+/// A message of a Flutter ARB file with its `@key` metadata.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArbMessage {
+    key: String,
+    text: String,
+    description: Option<String>,
+    placeholders: Vec<String>,
+}
+
+impl ArbMessage {
+    /// Get the key of the message.
+    pub fn get_key(&self) -> String {
+        self.key.clone()
+    }
+
+    /// Get the ICU text of the message.
+    pub fn get_text(&self) -> String {
+        self.text.clone()
+    }
+
+    /// Get the `description` of the metadata of the message.
+    pub fn get_description(&self) -> Option<String> {
+        self.description.clone()
+    }
+
+    /// Get the names of the `placeholders` of the metadata of the message, in the order of the
+    /// file.
+    pub fn get_placeholders(&self) -> Vec<String> {
+        self.placeholders.clone()
+    }
+}
+
+/// The messages of a Flutter ARB file like `app_en.arb`.
+///
+/// The ARB files are not a `Format` of the sources yet, so `load` and the sources cannot discover
+/// or read them. They are read on their own and their texts are added with
+/// `Languages::add_language`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Arb {
+    locale: Option<String>,
+    messages: Vec<ArbMessage>,
+}
+
+impl Arb {
+    /// Parse an ARB file, where the keys that start with `@` are the metadata of the messages
+    /// and the keys that start with `@@` are the global metadata like `@@locale`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::{Arb, Value};
+    ///
+    /// let arb = Arb::from_json_string(
+    ///     r#"{
+    ///         "@@locale": "es",
+    ///         "helloWorld": "¡Hola, mundo!",
+    ///         "@helloWorld": {
+    ///             "description": "The greeting of the home page."
+    ///         },
+    ///         "items": "{count, plural, =0{Sin elementos} one{1 elemento} other{{count} elementos}}",
+    ///         "@items": {
+    ///             "placeholders": {
+    ///                 "count": { "type": "int" }
+    ///             }
+    ///         }
+    ///     }"#,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(arb.get_locale(), Some(String::from("es")));
+    ///
+    /// let messages = arb.get_messages();
+    /// assert_eq!(messages.len(), 2);
+    /// assert_eq!(messages[0].get_key(), "helloWorld");
+    /// assert_eq!(
+    ///     messages[0].get_description(),
+    ///     Some(String::from("The greeting of the home page."))
+    /// );
+    /// assert_eq!(messages[1].get_placeholders(), vec!["count"]);
+    ///
+    /// let texts = arb.get_texts().unwrap();
+    /// assert_eq!(texts.get_language(), "es");
+    /// assert_eq!(
+    ///     texts.try_get_text("helloWorld"),
+    ///     Some(Value::String(String::from("¡Hola, mundo!")))
+    /// );
+    ///
+    /// assert!(Arb::from_json_string(r#"{ "count": 1 }"#).is_err());
+    /// ```
+    pub fn from_json_string(text: &str) -> anyhow::Result<Self> {
+        let data: Map<String, JsonValue> = serde_json::from_str(text)
+            .map_err(|e| anyhow::Error::msg(format!("Cannot parse the ARB file: {}", e)))?;
+
+        let mut locale = None;
+        let mut messages = Vec::new();
+        for (key, value) in data.iter() {
+            if key == "@@locale" {
+                locale = value.as_str().map(String::from);
+                continue;
+            }
+
+            if key.starts_with('@') {
+                continue;
+            }
+
+            let text = match value {
+                JsonValue::String(text) => text.clone(),
+                _ => {
+                    return Err(anyhow::Error::msg(format!(
+                        "The ARB message `{}` is not a text.",
+                        key
+                    )))
+                }
+            };
+
+            let metadata = data.get(&format!("@{}", key));
+            let description = metadata
+                .and_then(|metadata| metadata.get("description"))
+                .and_then(|description| description.as_str())
+                .map(String::from);
+
+            let placeholders = metadata
+                .and_then(|metadata| metadata.get("placeholders"))
+                .and_then(|placeholders| placeholders.as_object())
+                .map(|placeholders| placeholders.keys().cloned().collect())
+                .unwrap_or_default();
+
+            messages.push(ArbMessage {
+                key: key.clone(),
+                text,
+                description,
+                placeholders,
+            });
+        }
+
+        Ok(Self { locale, messages })
+    }
+
+    /// Read an ARB file. If it does not have the `@@locale` metadata, the locale is taken from
+    /// the end of the file name, like `pt_BR` in `my_app_pt_BR.arb` or `zh_Hant` in
+    /// `app_zh_Hant.arb`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::{Arb, Config, Languages, Value};
+    ///
+    /// #[cfg(feature = "with-json")]
+    /// fn main() {
+    ///     let directory = std::env::temp_dir().join("languages-rs-arb-read");
+    ///     std::fs::create_dir_all(&directory).unwrap();
+    ///
+    ///     let path = directory.join("my_app_pt_BR.arb");
+    ///     std::fs::write(&path, r#"{ "hi": "Olá" }"#).unwrap();
+    ///
+    ///     let arb = Arb::read(&path).unwrap();
+    ///     assert_eq!(arb.get_locale(), Some(String::from("pt_BR")));
+    ///
+    ///     let config = Config::new(&directory, vec![]).unwrap();
+    ///     let mut texts = Languages::new(&config);
+    ///     assert!(texts.add_language(arb.get_texts().unwrap()).is_ok());
+    ///     assert_eq!(
+    ///         texts.try_get_text_from_language("pt_BR", "hi").unwrap(),
+    ///         Some(Value::String(String::from("Olá")))
+    ///     );
+    /// }
+    ///
+    /// #[cfg(not(feature = "with-json"))]
+    /// fn main() {}
+    /// ```
+    pub fn read<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)
+            .map_err(|e| anyhow::Error::msg(format!("Cannot read `{}`: {}", path.display(), e)))?;
+
+        let mut arb = Self::from_json_string(&text).map_err(|e| {
+            anyhow::Error::msg(format!("Cannot parse `{}` file: {}", path.display(), e))
+        })?;
+
+        if arb.locale.is_none() {
+            arb.locale = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(get_file_locale);
+        }
+
+        Ok(arb)
+    }
+
+    /// Get the locale of the ARB file.
+    pub fn get_locale(&self) -> Option<String> {
+        self.locale.clone()
+    }
+
+    /// Set the locale of the ARB file.
+    pub fn set_locale(&mut self, locale: &str) {
+        self.locale = Some(String::from(locale));
+    }
+
+    /// Get the messages of the ARB file in the order of the file.
+    pub fn get_messages(&self) -> Vec<ArbMessage> {
+        self.messages.clone()
+    }
+
+    /// Get the texts of the messages for the locale of the ARB file, where the keys of the
+    /// messages are the keys of the texts.
+    pub fn get_texts(&self) -> anyhow::Result<LanguageTexts> {
+        let locale = match &self.locale {
+            Some(locale) => locale.clone(),
+            None => return Err(anyhow::Error::msg("The ARB file does not have a locale.")),
+        };
+
+        let texts = self
+            .messages
+            .iter()
+            .map(|message| (message.key.clone(), Value::String(message.text.clone())))
+            .collect();
+
+        LanguageTexts::new(locale, Value::Object(texts))
+    }
+}
+
+/// Get the locale at the end of the name of an ARB file, like `pt_BR` in `my_app_pt_BR`, with a
+/// language and an optional script and region.
+fn get_file_locale(stem: &str) -> Option<String> {
+    let segments: Vec<&str> = stem.split('_').collect();
+
+    let mut start = segments.len() - 1;
+    let is_region = |segment: &str| {
+        (segment.len() == 2 && segment.chars().all(|c| c.is_ascii_uppercase()))
+            || (segment.len() == 3 && segment.chars().all(|c| c.is_ascii_digit()))
+    };
+    if start > 0 && is_region(segments[start]) {
+        start -= 1;
+    }
+
+    let is_script = |segment: &str| {
+        segment.len() == 4
+            && segment.starts_with(|c: char| c.is_ascii_uppercase())
+            && segment.chars().skip(1).all(|c| c.is_ascii_lowercase())
+    };
+    if start > 0 && is_script(segments[start]) {
+        start -= 1;
+    }
+
+    let language = segments[start];
+    if (2..=3).contains(&language.len()) && language.chars().all(|c| c.is_ascii_lowercase()) {
+        Some(segments[start..].join("_"))
+    } else {
+        None
+    }
+}
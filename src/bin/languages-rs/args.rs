@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+/// The arguments of a subcommand, split in positional arguments and `--name value` options.
+pub struct Args {
+    positionals: Vec<String>,
+    options: HashMap<String, String>,
+    flags: Vec<String>,
+}
+
+impl Args {
+    /// Parse the arguments of a subcommand, where the names of `flags` are options without
+    /// value, like `--help`, and the names of `options` are options with a value. The other
+    /// options are rejected, so a mistyped option is not ignored.
+    pub fn parse(arguments: &[String], flags: &[&str], options: &[&str]) -> anyhow::Result<Self> {
+        let mut args = Self {
+            positionals: Vec::new(),
+            options: HashMap::new(),
+            flags: Vec::new(),
+        };
+
+        let mut arguments = arguments.iter();
+        while let Some(argument) = arguments.next() {
+            let name = match argument.strip_prefix("--") {
+                Some(name) => name,
+                None => {
+                    args.positionals.push(argument.clone());
+                    continue;
+                }
+            };
+
+            let option = name.split_once('=').map_or(name, |(name, _)| name);
+            if !flags.contains(&option) && !options.contains(&option) {
+                return Err(anyhow::Error::msg(format!(
+                    "The option `--{}` does not exist.\n\n{}",
+                    option,
+                    crate::USAGE
+                )));
+            }
+
+            if let Some((name, value)) = name.split_once('=') {
+                args.options.insert(String::from(name), String::from(value));
+            } else if flags.contains(&name) {
+                args.flags.push(String::from(name));
+            } else {
+                match arguments.next() {
+                    Some(value) => {
+                        args.options.insert(String::from(name), value.clone());
+                    }
+                    None => {
+                        return Err(anyhow::Error::msg(format!(
+                            "The option `--{}` needs a value.",
+                            name
+                        )))
+                    }
+                }
+            }
+        }
+
+        Ok(args)
+    }
+
+    /// Get a positional argument that must exist.
+    pub fn get_positional(&self, index: usize, name: &str) -> anyhow::Result<String> {
+        match self.positionals.get(index) {
+            Some(value) => Ok(value.clone()),
+            None => Err(anyhow::Error::msg(format!(
+                "The argument `<{}>` is required.",
+                name
+            ))),
+        }
+    }
+
+    /// Get the value of an option.
+    pub fn get_option(&self, name: &str) -> Option<String> {
+        self.options.get(name).cloned()
+    }
+
+    /// Get the values of an option separated by commas.
+    pub fn get_list_option(&self, name: &str) -> Vec<String> {
+        self.get_option(name)
+            .map(|value| {
+                value
+                    .split(',')
+                    .map(|item| item.trim())
+                    .filter(|item| !item.is_empty())
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Check if a flag is used.
+    pub fn has_flag(&self, name: &str) -> bool {
+        self.flags.iter().any(|flag| flag == name)
+    }
+}
@@ -0,0 +1,132 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use languages_rs::Format;
+
+use crate::{args::Args, USAGE};
+
+/// Convert a languages file, or every languages file of a directory, to the `--to` format,
+/// returning `false` if some values cannot be represented in that format.
+pub fn run(arguments: &[String]) -> anyhow::Result<bool> {
+    let args = Args::parse(arguments, &["help"], &["to", "output"])?;
+    if args.has_flag("help") {
+        println!("{}", USAGE);
+        return Ok(true);
+    }
+
+    let input = PathBuf::from(args.get_positional(0, "file|directory")?);
+
+    let target = match args.get_option("to") {
+        Some(extension) => Format::from_extension(&extension).ok_or_else(|| {
+            anyhow::Error::msg(format!("The format `{}` does not exist.", extension))
+        })?,
+        None => {
+            return Err(anyhow::Error::msg(
+                "The option `--to <format>` is required.",
+            ))
+        }
+    };
+
+    let files = if input.is_dir() {
+        get_directory_files(&input, target)?
+    } else {
+        vec![PathBuf::new()]
+    };
+
+    let output = args.get_option("output").map(PathBuf::from);
+
+    let mut success = true;
+    for file in files.iter() {
+        let (source, destination) = if input.is_dir() {
+            let destination = output.clone().unwrap_or_else(|| input.clone()).join(file);
+            (input.join(file), destination)
+        } else {
+            let destination = output
+                .clone()
+                .unwrap_or_else(|| input.with_extension(target.get_extension()));
+            (input.clone(), destination)
+        };
+
+        let format = get_format(&source)?;
+        let text = fs::read_to_string(&source).map_err(|e| {
+            anyhow::Error::msg(format!("Cannot read `{}`: {}", source.display(), e))
+        })?;
+
+        let conversion = format.convert(text, target).map_err(|e| {
+            anyhow::Error::msg(format!("Cannot convert `{}`: {}", source.display(), e))
+        })?;
+
+        for lossy_value in conversion.get_lossy_values() {
+            success = false;
+            println!("[{}] {}", source.display(), lossy_value);
+        }
+
+        let destination = destination.with_extension(target.get_extension());
+        if let Some(parent) = destination.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(&destination, conversion.get_text())?;
+        println!("{} -> {}", source.display(), destination.display());
+    }
+
+    if files.is_empty() {
+        println!(
+            "Cannot find languages files to convert in `{}`.",
+            input.display()
+        );
+    }
+
+    Ok(success)
+}
+
+/// Get the format of a file from its extension.
+fn get_format(path: &Path) -> anyhow::Result<Format> {
+    path.extension()
+        .and_then(|extension| Format::from_extension(&extension.to_string_lossy()))
+        .ok_or_else(|| {
+            anyhow::Error::msg(format!(
+                "Cannot know the format of `{}` from its extension.",
+                path.display()
+            ))
+        })
+}
+
+/// Get the `<lang>.<ext>` and `<lang>/<namespace>.<ext>` files of a directory that are not
+/// written in the target format, relative to the directory and sorted by name.
+fn get_directory_files(directory: &Path, target: Format) -> anyhow::Result<Vec<PathBuf>> {
+    let is_convertible = |path: &Path| {
+        let is_hidden = path
+            .file_name()
+            .map(|name| name.to_string_lossy().starts_with('.'))
+            .unwrap_or(true);
+
+        !is_hidden && path.is_file() && matches!(get_format(path), Ok(format) if format != target)
+    };
+
+    let mut files = Vec::new();
+    for entry in fs::read_dir(directory)? {
+        let path = entry?.path();
+        if path.is_dir()
+            && !path
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .starts_with('.')
+        {
+            for entry in fs::read_dir(&path)? {
+                let path = entry?.path();
+                if is_convertible(&path) {
+                    files.push(path.strip_prefix(directory)?.to_path_buf());
+                }
+            }
+        } else if is_convertible(&path) {
+            files.push(path.strip_prefix(directory)?.to_path_buf());
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+use crate::{args::Args, project::Project, USAGE};
+
+/// Print the percentage of the texts of the reference language that every language has
+/// translated, returning `false` if a language is below the `--min` percentage.
+pub fn run(arguments: &[String]) -> anyhow::Result<bool> {
+    let args = Args::parse(arguments, &["help"], &["languages", "reference", "min"])?;
+    if args.has_flag("help") {
+        println!("{}", USAGE);
+        return Ok(true);
+    }
+
+    let project = Project::load(&args)?;
+
+    let min: f64 = match args.get_option("min") {
+        Some(min) => min
+            .parse()
+            .map_err(|_| anyhow::Error::msg(format!("`{}` is not a percentage.", min)))?,
+        None => 0.0,
+    };
+
+    let reference = project.get_reference_texts()?.get_texts().flatten();
+
+    let mut success = project.errors.is_empty();
+    for (lang, e) in project.errors.iter() {
+        println!("{:<10} cannot be loaded: {}", lang, e);
+    }
+
+    for lang_texts in project.texts.iter() {
+        let texts: HashMap<String, String> = lang_texts.get_texts().flatten().into_iter().collect();
+
+        let translated = reference
+            .iter()
+            .filter(|(path, _)| {
+                texts
+                    .get(path)
+                    .map(|text| !text.trim().is_empty())
+                    .unwrap_or(false)
+            })
+            .count();
+
+        let percentage = if reference.is_empty() {
+            100.0
+        } else {
+            translated as f64 * 100.0 / reference.len() as f64
+        };
+
+        if percentage < min {
+            success = false;
+        }
+
+        println!(
+            "{:<10} {:>6.1}% ({}/{})",
+            lang_texts.get_language(),
+            percentage,
+            translated,
+            reference.len()
+        );
+    }
+
+    Ok(success)
+}
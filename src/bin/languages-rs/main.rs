@@ -0,0 +1,63 @@
+//! The `languages-rs` command to check and convert the languages directories, using the same
+//! parsers as the library.
+
+mod args;
+mod convert;
+mod coverage;
+mod project;
+mod scan;
+mod validate;
+
+use std::{env, process};
+
+const USAGE: &str = "Usage: languages-rs <command> [options]
+
+Commands:
+    validate <directory|manifest>    Check the parse errors, missing and extra keys and
+                                     placeholders of the languages.
+    coverage <directory|manifest>    Print the percentage of translated texts per language.
+    convert <file|directory>         Write the languages files in the `--to` format.
+    scan <directory|manifest>        Find the keys used in the Rust sources that do not exist
+                                     and the keys that are never used.
+
+Options:
+    --languages <list>    The languages separated by commas, discovered if not defined.
+    --reference <lang>    The reference language, the default language or `en` if not defined.
+    --min <percent>       The minimum coverage of the `coverage` command.
+    --to <format>         The format of the `convert` command, `json` or `toml`.
+    --output <path>       The file or directory of the converted files, next to the
+                          original files if not defined.
+    --sources <directory> The Rust sources of the `scan` command, `src` if not defined.
+    --functions <list>    Other functions that receive keys, like `translate:1` where
+                          `1` is the index of the key argument.
+    --macros <list>       The macros that receive keys, like `t` or `t:0`.
+    --help                Print this message.";
+
+fn main() {
+    let arguments: Vec<String> = env::args().skip(1).collect();
+
+    let result = match arguments.first().map(|command| command.as_str()) {
+        Some("validate") => validate::run(&arguments[1..]),
+        Some("coverage") => coverage::run(&arguments[1..]),
+        Some("convert") => convert::run(&arguments[1..]),
+        Some("scan") => scan::run(&arguments[1..]),
+        Some("--help") | Some("-h") | Some("help") => {
+            println!("{}", USAGE);
+            return;
+        }
+        Some(command) => Err(anyhow::Error::msg(format!(
+            "The command `{}` does not exist.\n\n{}",
+            command, USAGE
+        ))),
+        None => Err(anyhow::Error::msg(USAGE)),
+    };
+
+    match result {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(2);
+        }
+    }
+}
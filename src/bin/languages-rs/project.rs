@@ -0,0 +1,98 @@
+use std::path::Path;
+
+use languages_rs::{load_partial, Config, LanguageTexts, LoadingStrategy};
+
+use crate::args::Args;
+
+/// The languages of a directory or a manifest loaded for a command.
+pub struct Project {
+    pub texts: Vec<LanguageTexts>,
+    pub errors: Vec<(String, anyhow::Error)>,
+    pub reference: String,
+}
+
+impl Project {
+    /// Load the languages of the `<directory|manifest>` argument, keeping the languages that
+    /// cannot be parsed as errors.
+    pub fn load(args: &Args) -> anyhow::Result<Self> {
+        let path = args.get_positional(0, "directory|manifest")?;
+
+        let mut config = if Path::new(&path).is_file() {
+            Config::from_manifest(&path)?
+        } else {
+            Config::new(&path, vec![])?
+        };
+
+        let languages = args.get_list_option("languages");
+        if languages.is_empty() {
+            if config.get_languages().is_empty() || config.is_discover_languages() {
+                config.discover_languages()?;
+            }
+        } else {
+            for lang in config.get_languages() {
+                config.remove_language(&lang)?;
+            }
+
+            for lang in languages {
+                config.add_language(lang)?;
+            }
+        }
+
+        // Every language is checked, so they are loaded now and the discovered ones are kept in
+        // the configuration.
+        config.set_discover_languages(false);
+        config.set_loading_strategy(LoadingStrategy::Eager);
+
+        let (mut languages, mut errors) = load_partial(config.clone())?;
+
+        let reference = match args
+            .get_option("reference")
+            .or_else(|| config.get_default_language())
+        {
+            Some(reference) => reference,
+            None if config.get_languages().contains(&String::from("en")) => String::from("en"),
+            None => match config.get_languages().first() {
+                Some(lang) => lang.clone(),
+                None => {
+                    return Err(anyhow::Error::msg(format!(
+                        "Cannot find languages in `{}`.",
+                        path
+                    )))
+                }
+            },
+        };
+
+        let mut texts = Vec::new();
+        for lang in config.get_languages() {
+            if errors.iter().any(|(failed, _)| *failed == lang) {
+                continue;
+            }
+
+            match languages.try_get_language(&lang) {
+                Ok(lang_texts) => texts.push(lang_texts),
+                Err(e) => errors.push((lang, e)),
+            }
+        }
+
+        Ok(Self {
+            texts,
+            errors,
+            reference,
+        })
+    }
+
+    /// Get the texts of the reference language.
+    pub fn get_reference_texts(&self) -> anyhow::Result<LanguageTexts> {
+        match self
+            .texts
+            .iter()
+            .find(|lang_texts| lang_texts.get_language() == self.reference)
+        {
+            Some(lang_texts) => Ok(lang_texts.clone()),
+            None => Err(anyhow::Error::msg(format!(
+                "Cannot load the `{}` reference lang.",
+                self.reference
+            ))),
+        }
+    }
+}
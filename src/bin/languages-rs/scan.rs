@@ -0,0 +1,77 @@
+use languages_rs::{KeyReport, KeyScanner};
+
+use crate::{args::Args, project::Project, USAGE};
+
+/// Find the keys used in the Rust sources that the reference language does not have and the
+/// keys of the reference language that are never used, returning `false` if there are any.
+pub fn run(arguments: &[String]) -> anyhow::Result<bool> {
+    let args = Args::parse(
+        arguments,
+        &["help"],
+        &["languages", "reference", "sources", "functions", "macros"],
+    )?;
+    if args.has_flag("help") {
+        println!("{}", USAGE);
+        return Ok(true);
+    }
+
+    let project = Project::load(&args)?;
+
+    let mut scanner = KeyScanner::new();
+    for (name, argument) in get_calls(&args, "functions")? {
+        scanner.add_function(&name, argument);
+    }
+
+    for (name, argument) in get_calls(&args, "macros")? {
+        scanner.add_macro(&name, argument);
+    }
+
+    let sources = args
+        .get_option("sources")
+        .unwrap_or_else(|| String::from("src"));
+    let usages = scanner.scan_directory(&sources)?;
+
+    let report = KeyReport::compare(&usages, &project.get_reference_texts()?);
+    for usage in report.get_missing_keys() {
+        println!("[{}] missing {}", project.reference, usage);
+    }
+
+    for key in report.get_unused_keys() {
+        println!("[{}] unused `{}`", project.reference, key);
+    }
+
+    if report.is_clean() {
+        println!(
+            "{} keys used in `{}` exist in `{}`.",
+            usages.len(),
+            sources,
+            project.reference
+        );
+    } else {
+        println!(
+            "Found {} missing keys and {} unused keys.",
+            report.get_missing_keys().len(),
+            report.get_unused_keys().len()
+        );
+    }
+
+    Ok(report.is_clean())
+}
+
+/// Get the `name` or `name:argument` items of a list option, where the argument of the key is
+/// the first one if not defined.
+fn get_calls(args: &Args, option: &str) -> anyhow::Result<Vec<(String, usize)>> {
+    args.get_list_option(option)
+        .into_iter()
+        .map(|item| match item.split_once(':') {
+            Some((name, argument)) => match argument.parse() {
+                Ok(argument) => Ok((String::from(name), argument)),
+                Err(_) => Err(anyhow::Error::msg(format!(
+                    "`{}` is not an argument index of `--{}`.",
+                    argument, option
+                ))),
+            },
+            None => Ok((item, 0)),
+        })
+        .collect()
+}
@@ -0,0 +1,37 @@
+use languages_rs::ConsistencyReport;
+
+use crate::{args::Args, project::Project, USAGE};
+
+/// Check the parse errors, the missing and extra keys and the placeholders of the languages,
+/// returning `false` if there are problems.
+pub fn run(arguments: &[String]) -> anyhow::Result<bool> {
+    let args = Args::parse(arguments, &["help"], &["languages", "reference"])?;
+    if args.has_flag("help") {
+        println!("{}", USAGE);
+        return Ok(true);
+    }
+
+    let project = Project::load(&args)?;
+
+    for (lang, e) in project.errors.iter() {
+        println!("[{}] cannot be loaded: {}", lang, e);
+    }
+
+    let report = ConsistencyReport::compare(&project.get_reference_texts()?, &project.texts);
+    for issue in report.get_issues() {
+        println!("{}", issue);
+    }
+
+    let problems = project.errors.len() + report.get_issues().len();
+    if problems == 0 {
+        println!(
+            "{} languages are valid compared with `{}`.",
+            project.texts.len(),
+            project.reference
+        );
+    } else {
+        println!("Found {} problems.", problems);
+    }
+
+    Ok(problems == 0)
+}
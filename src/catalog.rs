@@ -0,0 +1,386 @@
+use std::{collections::HashMap, fs, ops::Range, path::Path};
+
+use crate::{source::write_atomic, Config, LanguageTexts, Value};
+
+/// The first bytes of every catalog.
+const MAGIC: &[u8; 8] = b"LRSCAT\0\0";
+
+/// The version of the catalog format. Catalogs with other versions are rejected.
+const VERSION: u32 = 1;
+
+/// The size of the magic bytes, the version and the checksum.
+const HEADER_SIZE: usize = 16;
+
+const TAG_STRING: u8 = 0;
+const TAG_ARRAY: u8 = 1;
+const TAG_OBJECT: u8 = 2;
+
+/// A precompiled binary file with the texts of several languages, to load them without parsing
+/// `JSON` or `TOML` files.
+///
+/// A catalog starts with the `LRSCAT` magic bytes, the version of the format and a CRC-32
+/// checksum of the rest of the file, followed by an index with the position of every language.
+/// Only the index is read when the catalog is opened, and the texts of a language are decoded
+/// when they are used.
+///
+/// The catalogs can be written in a build step and used with `Source::Catalog`.
+#[derive(Clone, Debug)]
+pub struct Catalog {
+    data: Vec<u8>,
+    index: Vec<(String, Range<usize>)>,
+}
+
+impl Catalog {
+    /// Create a catalog with the texts of several languages.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::{Catalog, LanguageTexts, Value};
+    ///
+    /// let mut data = HashMap::new();
+    /// data.insert(String::from("hi"), Value::String(String::from("Hi")));
+    ///
+    /// let texts_en = LanguageTexts::new(String::from("en"), Value::Object(data)).unwrap();
+    ///
+    /// let catalog = Catalog::from_languages(&[texts_en.clone()]);
+    /// assert_eq!(catalog.get_languages(), vec![String::from("en")]);
+    /// assert_eq!(catalog.get_language("en").unwrap(), Some(texts_en.get_texts()));
+    /// assert_eq!(catalog.get_language("es").unwrap(), None);
+    /// ```
+    pub fn from_languages(languages: &[LanguageTexts]) -> Self {
+        let mut data = Vec::new();
+        let mut index = Vec::new();
+
+        for lang_texts in languages.iter() {
+            let start = data.len();
+            encode_value(&lang_texts.get_texts(), &mut data);
+            index.push((lang_texts.get_language(), start..data.len()));
+        }
+
+        Self { data, index }
+    }
+
+    /// Create a catalog with the texts of every language of a configuration, like in a build
+    /// script that writes the catalog used by the application.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::{Catalog, Config, Source};
+    ///
+    /// #[cfg(feature = "with-json")]
+    /// fn main() {
+    ///     let config = Config::new("examples/texts/json", vec!["en", "es"]).unwrap();
+    ///
+    ///     let directory = std::env::temp_dir().join("languages-rs-catalog-from-config");
+    ///     std::fs::create_dir_all(&directory).unwrap();
+    ///
+    ///     let path = directory.join("languages.bin");
+    ///     assert!(Catalog::from_config(&config).unwrap().write(&path).is_ok());
+    ///
+    ///     // The directory only has the catalog, so the texts are read from it.
+    ///     let mut config = Config::new(&directory, vec!["en"]).unwrap();
+    ///     assert!(config.add_source(Source::Catalog(path)).is_ok());
+    ///
+    ///     let mut texts = languages_rs::load(config).unwrap();
+    ///     assert!(texts.try_get_text_from_language("en", "greeting").unwrap().is_some());
+    /// }
+    ///
+    /// #[cfg(not(feature = "with-json"))]
+    /// fn main() {}
+    /// ```
+    pub fn from_config(config: &Config) -> anyhow::Result<Self> {
+        let mut languages = crate::load(config.clone())?;
+
+        let mut lang_texts = Vec::new();
+        for lang in config.get_languages().iter() {
+            lang_texts.push(languages.try_get_language(lang)?);
+        }
+
+        Ok(Self::from_languages(&lang_texts))
+    }
+
+    /// Read a catalog from its bytes, checking the version and the checksum.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::{Catalog, LanguageTexts, Value};
+    ///
+    /// let texts_en = LanguageTexts::new(String::from("en"), Value::Object(HashMap::new())).unwrap();
+    /// let mut bytes = Catalog::from_languages(&[texts_en]).to_bytes();
+    ///
+    /// let catalog = Catalog::from_bytes(bytes.clone());
+    /// assert!(catalog.is_ok());
+    ///
+    /// // Change a byte of the catalog.
+    /// let last = bytes.len() - 1;
+    /// bytes[last] ^= 1;
+    ///
+    /// let catalog = Catalog::from_bytes(bytes);
+    /// assert!(catalog.is_err());
+    /// ```
+    pub fn from_bytes(bytes: Vec<u8>) -> anyhow::Result<Self> {
+        if bytes.len() < HEADER_SIZE || &bytes[..8] != MAGIC {
+            return Err(anyhow::Error::msg("The file is not a languages catalog."));
+        }
+
+        let version = u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]);
+        if version != VERSION {
+            return Err(anyhow::Error::msg(format!(
+                "The catalog version {} is not supported, it must be written again with the version {}.",
+                version, VERSION
+            )));
+        }
+
+        let checksum = u32::from_le_bytes([bytes[12], bytes[13], bytes[14], bytes[15]]);
+        if checksum != crc32(&bytes[HEADER_SIZE..]) {
+            return Err(anyhow::Error::msg(
+                "The checksum of the catalog is not valid, the file is corrupt.",
+            ));
+        }
+
+        let mut reader = Reader::new(&bytes, HEADER_SIZE);
+
+        let mut index = Vec::new();
+        for _ in 0..reader.read_u32()? {
+            let language = reader.read_string()?;
+            let start = reader.read_u64()? as usize;
+            let end = reader.read_u64()? as usize;
+            index.push((language, start..end));
+        }
+
+        let data = bytes[reader.position..].to_vec();
+        if index
+            .iter()
+            .any(|(_, range)| range.start > range.end || range.end > data.len())
+        {
+            return Err(anyhow::Error::msg("The index of the catalog is not valid."));
+        }
+
+        Ok(Self { data, index })
+    }
+
+    /// Get the bytes of the catalog.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut body = Vec::new();
+        write_u32(self.index.len() as u32, &mut body);
+        for (language, range) in self.index.iter() {
+            write_string(language, &mut body);
+            body.extend_from_slice(&(range.start as u64).to_le_bytes());
+            body.extend_from_slice(&(range.end as u64).to_le_bytes());
+        }
+
+        body.extend_from_slice(&self.data);
+
+        let mut bytes = Vec::with_capacity(HEADER_SIZE + body.len());
+        bytes.extend_from_slice(MAGIC);
+        write_u32(VERSION, &mut bytes);
+        write_u32(crc32(&body), &mut bytes);
+        bytes.extend_from_slice(&body);
+
+        bytes
+    }
+
+    /// Read a catalog file, checking the version and the checksum.
+    pub fn read<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let bytes = fs::read(path).map_err(|e| {
+            anyhow::Error::msg(format!(
+                "Cannot read the catalog `{}`: {}",
+                path.display(),
+                e
+            ))
+        })?;
+
+        Self::from_bytes(bytes).map_err(|e| {
+            anyhow::Error::msg(format!(
+                "Cannot read the catalog `{}`: {}",
+                path.display(),
+                e
+            ))
+        })
+    }
+
+    /// Write the catalog to a file atomically.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::{Catalog, LanguageTexts, Value};
+    ///
+    /// let texts_en = LanguageTexts::new(String::from("en"), Value::Object(HashMap::new())).unwrap();
+    /// let path = std::env::temp_dir().join("languages-rs-catalog-write.bin");
+    ///
+    /// assert!(Catalog::from_languages(&[texts_en]).write(&path).is_ok());
+    ///
+    /// let catalog = Catalog::read(&path);
+    /// assert!(catalog.is_ok());
+    /// assert_eq!(catalog.unwrap().get_languages(), vec![String::from("en")]);
+    /// ```
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> anyhow::Result<()> {
+        let bytes = self.to_bytes();
+        write_atomic(path.as_ref(), &bytes)
+    }
+
+    /// Get the languages of the catalog.
+    pub fn get_languages(&self) -> Vec<String> {
+        self.index
+            .iter()
+            .map(|(language, _)| language.clone())
+            .collect()
+    }
+
+    /// Decode the texts of a language, or `None` if the catalog does not have the language.
+    pub fn get_language(&self, lang: &str) -> anyhow::Result<Option<Value>> {
+        let range = match self.index.iter().find(|(language, _)| language == lang) {
+            Some((_, range)) => range.clone(),
+            None => return Ok(None),
+        };
+
+        let mut reader = Reader::new(&self.data[range.clone()], 0);
+        let value = reader.read_value()?;
+        if reader.position != range.len() {
+            return Err(anyhow::Error::msg(format!(
+                "The texts of the `{}` lang in the catalog are not valid.",
+                lang
+            )));
+        }
+
+        Ok(Some(value))
+    }
+}
+
+fn write_u32(number: u32, bytes: &mut Vec<u8>) {
+    bytes.extend_from_slice(&number.to_le_bytes());
+}
+
+fn write_string(text: &str, bytes: &mut Vec<u8>) {
+    write_u32(text.len() as u32, bytes);
+    bytes.extend_from_slice(text.as_bytes());
+}
+
+fn encode_value(value: &Value, bytes: &mut Vec<u8>) {
+    match value {
+        Value::String(text) => {
+            bytes.push(TAG_STRING);
+            write_string(text, bytes);
+        }
+        Value::Array(values) => {
+            bytes.push(TAG_ARRAY);
+            write_u32(values.len() as u32, bytes);
+            for value in values.iter() {
+                encode_value(value, bytes);
+            }
+        }
+        Value::Object(data) => {
+            bytes.push(TAG_OBJECT);
+            write_u32(data.len() as u32, bytes);
+
+            // Sort the keys so the same texts always write the same catalog.
+            let mut keys: Vec<&String> = data.keys().collect();
+            keys.sort();
+
+            for key in keys {
+                write_string(key, bytes);
+                encode_value(&data[key], bytes);
+            }
+        }
+    }
+}
+
+/// A cursor over the bytes of a catalog that fails instead of reading out of bounds.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8], position: usize) -> Self {
+        Self { bytes, position }
+    }
+
+    fn read_bytes(&mut self, length: usize) -> anyhow::Result<&'a [u8]> {
+        let end = self
+            .position
+            .checked_add(length)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or_else(|| anyhow::Error::msg("The catalog ends unexpectedly."))?;
+
+        let bytes = &self.bytes[self.position..end];
+        self.position = end;
+
+        Ok(bytes)
+    }
+
+    fn read_u8(&mut self) -> anyhow::Result<u8> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    fn read_u32(&mut self) -> anyhow::Result<u32> {
+        let bytes = self.read_bytes(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn read_u64(&mut self) -> anyhow::Result<u64> {
+        let mut number = [0; 8];
+        number.copy_from_slice(self.read_bytes(8)?);
+        Ok(u64::from_le_bytes(number))
+    }
+
+    fn read_string(&mut self) -> anyhow::Result<String> {
+        let length = self.read_u32()? as usize;
+        Ok(String::from_utf8(self.read_bytes(length)?.to_vec())?)
+    }
+
+    fn read_value(&mut self) -> anyhow::Result<Value> {
+        match self.read_u8()? {
+            TAG_STRING => Ok(Value::String(self.read_string()?)),
+            TAG_ARRAY => {
+                let length = self.read_u32()?;
+
+                let mut values = Vec::new();
+                for _ in 0..length {
+                    values.push(self.read_value()?);
+                }
+
+                Ok(Value::Array(values))
+            }
+            TAG_OBJECT => {
+                let length = self.read_u32()?;
+
+                let mut data = HashMap::new();
+                for _ in 0..length {
+                    let key = self.read_string()?;
+                    data.insert(key, self.read_value()?);
+                }
+
+                Ok(Value::Object(data))
+            }
+            tag => Err(anyhow::Error::msg(format!(
+                "The value type {} of the catalog is not valid.",
+                tag
+            ))),
+        }
+    }
+}
+
+/// Compute the CRC-32 (IEEE) checksum of some bytes.
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in bytes.iter() {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+
+    !crc
+}
@@ -1,46 +1,200 @@
-use std::{env, path::Path};
+mod config_builder;
+mod manifest;
+
+pub use config_builder::ConfigBuilder;
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+use crate::{source::SourceFiles, Format, Source};
+
+pub(crate) use manifest::MANIFEST_FILES;
+
+use manifest::Manifest;
+
+/// The moment when `load` reads the texts of the languages.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LoadingStrategy {
+    /// Read the texts of every language in `load`.
+    Eager,
+    /// Read the texts of a language the first time it is used.
+    Lazy,
+}
 
 #[derive(Clone)]
 pub struct Config {
-    directory: String,
+    directory: PathBuf,
+    sources: Vec<Source>,
     languages: Vec<String>,
+    default_language: Option<String>,
+    fallback_languages: Vec<String>,
+    formats: Vec<Format>,
+    loading_strategy: LoadingStrategy,
+    discover_languages: bool,
+    include_filters: Vec<String>,
+    exclude_filters: Vec<String>,
 }
 
 impl Config {
-    /// Create a new configuration.
+    /// Create a builder to set several options of a configuration and validate them at once.
     ///
     /// # Example
-    /// ```rust, ignore
+    /// ```rust
+    /// use languages_rs::Config;
+    ///
+    /// let config = Config::builder()
+    ///     .directory("examples/texts/json")
+    ///     .languages(vec!["en", "es"])
+    ///     .default_language("en")
+    ///     .build();
+    /// assert!(config.is_ok());
+    /// ```
+    pub fn builder() -> ConfigBuilder {
+        ConfigBuilder::new()
+    }
+
+    /// Create a new configuration. The directory can be absolute or relative to the current
+    /// directory of the process.
+    ///
+    /// # Example
+    /// ```rust
     /// use languages_rs::Config;
     ///
-    /// let config: Config = match Config::new("languages", vec!["en"]) {
+    /// let config: Config = match Config::new("examples/texts/json", vec!["en"]) {
     ///     Ok(config) => config,
     ///     Err(e) => {
     ///         eprintln!("Error: {}", e);
     ///         return;
     ///     },
     /// };
+    ///
+    /// assert!(Config::new("/path/that/does/not/exist", vec!["en"]).is_err());
     /// ```
-    pub fn new(directory: &str, languages: Vec<&str>) -> anyhow::Result<Self> {
-        let path = Path::new(&env::current_dir()?).join(directory);
-        if !path.exists() {
-            return Err(anyhow::Error::msg(format!(
-                "Cannot find `{}` directory.",
-                path.display()
-            )));
-        } else if !path.is_dir() {
-            return Err(anyhow::Error::msg(format!(
-                "The path `{}` is not a directory.",
-                path.display()
-            )));
-        }
+    pub fn new<P: AsRef<Path>>(directory: P, languages: Vec<&str>) -> anyhow::Result<Self> {
+        let path = directory.as_ref().to_path_buf();
+        check_directory(&path)?;
 
         Ok(Self {
-            directory: path.display().to_string(),
+            directory: path,
+            sources: Vec::new(),
             languages: languages.iter().map(|e| String::from(*e)).collect(),
+            default_language: None,
+            fallback_languages: Vec::new(),
+            formats: Format::enabled_formats(),
+            loading_strategy: LoadingStrategy::Eager,
+            discover_languages: false,
+            include_filters: Vec::new(),
+            exclude_filters: Vec::new(),
         })
     }
 
+    /// Create a new configuration with a directory relative to the directory of the current
+    /// executable, useful for services that are not started from their own directory.
+    ///
+    /// # Example
+    /// ```rust, ignore
+    /// use languages_rs::Config;
+    ///
+    /// // Uses `/opt/app/languages` if the executable is `/opt/app/app`.
+    /// let config = Config::from_executable_dir("languages", vec!["en"]);
+    /// assert!(config.is_ok());
+    /// ```
+    pub fn from_executable_dir<P: AsRef<Path>>(
+        directory: P,
+        languages: Vec<&str>,
+    ) -> anyhow::Result<Self> {
+        let executable = env::current_exe()?;
+        let executable_directory = match executable.parent() {
+            Some(executable_directory) => executable_directory,
+            None => {
+                return Err(anyhow::Error::msg(format!(
+                    "Cannot find the directory of the `{}` executable.",
+                    executable.display()
+                )))
+            }
+        };
+
+        Self::new(executable_directory.join(directory), languages)
+    }
+
+    /// Create a new configuration with a directory relative to the `CARGO_MANIFEST_DIR`
+    /// environment variable, which Cargo defines when it runs the tests, examples and binaries
+    /// of a package.
+    ///
+    /// Binaries that are not run with Cargo can use the `env!` macro instead, like
+    /// `Config::new(concat!(env!("CARGO_MANIFEST_DIR"), "/languages"), vec!["en"])`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::Config;
+    ///
+    /// let config = Config::from_cargo_manifest_dir("examples/texts/json", vec!["en"]);
+    /// assert!(config.is_ok());
+    /// ```
+    pub fn from_cargo_manifest_dir<P: AsRef<Path>>(
+        directory: P,
+        languages: Vec<&str>,
+    ) -> anyhow::Result<Self> {
+        match env::var_os("CARGO_MANIFEST_DIR") {
+            Some(manifest_directory) => {
+                Self::new(Path::new(&manifest_directory).join(directory), languages)
+            }
+            None => Err(anyhow::Error::msg(
+                "The `CARGO_MANIFEST_DIR` environment variable is not defined.",
+            )),
+        }
+    }
+
+    /// Create a configuration from a `languages.toml` or `i18n.json` manifest, so the languages
+    /// can change without recompiling. The relative directories of the manifest are joined to the
+    /// directory of the manifest, and `languages/` is used if it has no directory.
+    ///
+    /// Every option can be replaced with an environment variable, using commas to separate the
+    /// lists: `LANGUAGES_RS_DIRECTORY`, `LANGUAGES_RS_SOURCES`, `LANGUAGES_RS_LANGUAGES`,
+    /// `LANGUAGES_RS_DEFAULT_LANGUAGE`, `LANGUAGES_RS_FALLBACK_LANGUAGES`, `LANGUAGES_RS_FORMATS`,
+    /// `LANGUAGES_RS_LOADING_STRATEGY`, `LANGUAGES_RS_DISCOVER_LANGUAGES`,
+    /// `LANGUAGES_RS_INCLUDE_FILTERS` and `LANGUAGES_RS_EXCLUDE_FILTERS`.
+    ///
+    /// # Manifest
+    /// ```toml
+    /// directory = "texts/toml"
+    /// languages = ["en", "es"]
+    /// default_language = "en"
+    /// fallback_languages = ["en"]
+    /// formats = ["toml"]
+    /// loading_strategy = "lazy"
+    /// ```
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::{Config, LoadingStrategy};
+    ///
+    /// #[cfg(feature = "with-toml")]
+    /// fn main() {
+    ///     std::env::set_var("LANGUAGES_RS_LANGUAGES", "en");
+    ///
+    ///     let config = Config::from_manifest("examples/languages.toml").unwrap();
+    ///     assert_eq!(config.get_languages(), vec![String::from("en")]);
+    ///     assert_eq!(config.get_default_language(), Some(String::from("en")));
+    ///     assert_eq!(config.get_loading_strategy(), LoadingStrategy::Lazy);
+    /// }
+    ///
+    /// #[cfg(not(feature = "with-toml"))]
+    /// fn main() {
+    ///     assert!(Config::from_manifest("examples/languages.toml").is_err());
+    /// }
+    /// ```
+    pub fn from_manifest<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let mut manifest = Manifest::read(path)?;
+        manifest.apply_env_overrides()?;
+
+        let base = path.parent().unwrap_or_else(|| Path::new(""));
+        manifest.into_builder(base)?.build()
+    }
+
     /// Get the default configuration.
     ///
     /// # Default
@@ -62,8 +216,9 @@ impl Config {
     ///     },
     /// };
     /// ```
+    #[allow(clippy::should_implement_trait)]
     pub fn default() -> anyhow::Result<Self> {
-        let path = Path::new(&env::current_dir()?).join("languages");
+        let path = PathBuf::from("languages");
         if !path.exists() {
             std::fs::create_dir(&path)?;
         } else if !path.is_dir() {
@@ -74,59 +229,135 @@ impl Config {
         }
 
         Ok(Self {
-            directory: path.display().to_string(),
+            directory: path,
+            sources: Vec::new(),
             languages: Vec::new(),
+            default_language: None,
+            fallback_languages: Vec::new(),
+            formats: Format::enabled_formats(),
+            loading_strategy: LoadingStrategy::Eager,
+            discover_languages: false,
+            include_filters: Vec::new(),
+            exclude_filters: Vec::new(),
         })
     }
 
     /// Get the languages directory.
     ///
     /// # Example
-    /// ```rust, ignore
-    /// use std::{env, path};
+    /// ```rust
+    /// use std::path::PathBuf;
     ///
     /// use languages_rs::Config;
     ///
-    /// let config = Config::default().unwrap();
-    /// assert_eq!(
-    ///     config.get_directory(),
-    ///     format!(
-    ///         "{}{}languages",
-    ///         env::current_dir().unwrap().display(),
-    ///         path::MAIN_SEPARATOR,
-    ///     ),
-    /// );
+    /// let config = Config::new("examples/texts/json", vec!["en"]).unwrap();
+    /// assert_eq!(config.get_directory(), PathBuf::from("examples/texts/json"));
     /// ```
-    pub fn get_directory(&self) -> String {
+    pub fn get_directory(&self) -> PathBuf {
         self.directory.clone()
     }
 
     /// Change the languages directory.
     ///
     /// # Example
+    /// ```rust
+    /// use languages_rs::Config;
+    ///
+    /// let mut config = Config::new("examples/texts/json", vec!["en"]).unwrap();
+    /// assert!(config.set_directory("examples/texts/toml").is_ok());
+    /// ```
+    pub fn set_directory<P: AsRef<Path>>(&mut self, new_directory: P) -> anyhow::Result<()> {
+        let path = new_directory.as_ref().to_path_buf();
+        check_directory(&path)?;
+
+        self.directory = path;
+        Ok(())
+    }
+
+    /// Get the sources of the languages texts in order of precedence, where the languages
+    /// directory is the first one and the texts of the later sources override the texts of the
+    /// earlier ones key by key.
+    ///
+    /// # Example
     /// ```rust, ignore
-    /// use std::env;
+    /// use languages_rs::{Config, Source};
     ///
-    /// use languages_rs::Config;
+    /// let mut config = Config::default().unwrap();
+    /// assert_eq!(config.get_sources(), vec![Source::Directory(config.get_directory())]);
+    /// ```
+    pub fn get_sources(&self) -> Vec<Source> {
+        let mut sources = vec![Source::Directory(self.directory.clone())];
+        sources.extend(self.sources.iter().cloned());
+
+        sources
+    }
+
+    /// Add a source of the languages texts, overriding the texts of the previous sources.
+    ///
+    /// # Example
+    /// ```rust, ignore
+    /// use std::path::PathBuf;
+    ///
+    /// use languages_rs::{Config, Source};
     ///
     /// let mut config = Config::default().unwrap();
-    /// assert!(config.set_directory("languages").is_ok());
+    /// assert!(config.add_source(Source::Directory(PathBuf::from("/usr/share/app/languages"))).is_ok());
+    /// assert_eq!(config.get_sources().len(), 2);
     /// ```
-    pub fn set_directory(&mut self, new_directory: &str) -> anyhow::Result<()> {
-        let path = Path::new(&env::current_dir()?).join(new_directory);
-        if !path.exists() {
+    pub fn add_source(&mut self, source: Source) -> anyhow::Result<()> {
+        if self.get_sources().contains(&source) {
             return Err(anyhow::Error::msg(format!(
-                "Cannot find `{}` directory.",
-                path.display()
+                "The source {} already exists.",
+                source
             )));
-        } else if !path.is_dir() {
+        }
+
+        self.sources.push(source);
+        Ok(())
+    }
+
+    /// Add a directory of languages texts, overriding the texts of the previous sources.
+    ///
+    /// # Example
+    /// ```rust, ignore
+    /// use languages_rs::Config;
+    ///
+    /// let mut config = Config::default().unwrap();
+    ///
+    /// // The texts of `overrides/en.json` override the texts of `languages/en.json`.
+    /// assert!(config.add_directory("overrides").is_ok());
+    /// ```
+    pub fn add_directory<P: AsRef<Path>>(&mut self, directory: P) -> anyhow::Result<()> {
+        let path = directory.as_ref().to_path_buf();
+        check_directory(&path)?;
+
+        self.add_source(Source::Directory(path))
+    }
+
+    /// Remove a source added with `add_source` or `add_directory`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::path::PathBuf;
+    ///
+    /// use languages_rs::{Config, Source};
+    ///
+    /// let mut config = Config::new("examples/texts/json", vec!["en"]).unwrap();
+    /// assert!(config.add_directory("examples/texts/toml").is_ok());
+    ///
+    /// let source = Source::Directory(PathBuf::from("examples/texts/toml"));
+    /// assert!(config.remove_source(&source).is_ok());
+    /// assert_eq!(config.get_sources().len(), 1);
+    /// ```
+    pub fn remove_source(&mut self, source: &Source) -> anyhow::Result<()> {
+        if !self.sources.contains(source) {
             return Err(anyhow::Error::msg(format!(
-                "The path `{}` is not a directory.",
-                path.display()
+                "Cannot remove the source {}.",
+                source
             )));
         }
 
-        self.directory = path.display().to_string();
+        self.sources.retain(|e| e != source);
         Ok(())
     }
 
@@ -165,4 +396,518 @@ impl Config {
         self.languages.push(language);
         Ok(())
     }
+
+    /// Remove a language from the languages list, and from the default and fallback languages.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::Config;
+    ///
+    /// let mut config = Config::new("examples/texts/json", vec!["en", "es"]).unwrap();
+    /// assert!(config.remove_language("es").is_ok());
+    /// assert_eq!(config.get_languages(), vec![String::from("en")]);
+    ///
+    /// assert!(config.remove_language("es").is_err());
+    /// ```
+    pub fn remove_language(&mut self, language: &str) -> anyhow::Result<()> {
+        if !self.languages.iter().any(|e| e == language) {
+            return Err(anyhow::Error::msg(format!(
+                "Cannot find the `{}` lang.",
+                language
+            )));
+        }
+
+        self.languages.retain(|e| e != language);
+        self.fallback_languages.retain(|e| e != language);
+        if self.default_language.as_deref() == Some(language) {
+            self.default_language = None;
+        }
+
+        Ok(())
+    }
+
+    /// Get the default language.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::Config;
+    ///
+    /// let config = Config::new("examples/texts/json", vec!["en"]).unwrap();
+    /// assert_eq!(config.get_default_language(), None);
+    /// ```
+    pub fn get_default_language(&self) -> Option<String> {
+        self.default_language.clone()
+    }
+
+    /// Change the default language, which must be one of the languages.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::Config;
+    ///
+    /// let mut config = Config::new("examples/texts/json", vec!["en", "es"]).unwrap();
+    /// assert!(config.set_default_language(String::from("es")).is_ok());
+    /// assert_eq!(config.get_default_language(), Some(String::from("es")));
+    ///
+    /// assert!(config.set_default_language(String::from("fr")).is_err());
+    /// ```
+    pub fn set_default_language(&mut self, language: String) -> anyhow::Result<()> {
+        if !self.languages.contains(&language) {
+            return Err(anyhow::Error::msg(format!(
+                "Cannot find the `{}` lang.",
+                language
+            )));
+        }
+
+        self.default_language = Some(language);
+        Ok(())
+    }
+
+    /// Get the languages used when a language does not have a text, in order.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::Config;
+    ///
+    /// let config = Config::new("examples/texts/json", vec!["en", "es"]).unwrap();
+    /// assert!(config.get_fallback_languages().is_empty());
+    /// ```
+    pub fn get_fallback_languages(&self) -> Vec<String> {
+        self.fallback_languages.clone()
+    }
+
+    /// Add a language to use when a language does not have a text. It must be one of the
+    /// languages.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::Config;
+    ///
+    /// let mut config = Config::new("examples/texts/json", vec!["en", "es"]).unwrap();
+    /// assert!(config.add_fallback_language(String::from("en")).is_ok());
+    /// assert_eq!(config.get_fallback_languages(), vec![String::from("en")]);
+    ///
+    /// assert!(config.add_fallback_language(String::from("fr")).is_err());
+    /// ```
+    pub fn add_fallback_language(&mut self, language: String) -> anyhow::Result<()> {
+        if !self.languages.contains(&language) {
+            return Err(anyhow::Error::msg(format!(
+                "Cannot find the `{}` lang.",
+                language
+            )));
+        } else if self.fallback_languages.contains(&language) {
+            return Err(anyhow::Error::msg(format!(
+                "The fallback language `{}` already exists.",
+                language
+            )));
+        }
+
+        self.fallback_languages.push(language);
+        Ok(())
+    }
+
+    /// Get the formats of the languages files, in order of preference.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::{Config, Format};
+    ///
+    /// let config = Config::new("examples/texts/json", vec!["en"]).unwrap();
+    /// assert_eq!(config.get_formats(), Format::enabled_formats());
+    /// ```
+    pub fn get_formats(&self) -> Vec<Format> {
+        self.formats.clone()
+    }
+
+    /// Change the formats of the languages files, in order of preference. Every format must be
+    /// enabled with its Cargo feature.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::{Config, Format};
+    ///
+    /// let mut config = Config::new("examples/texts/json", vec!["en"]).unwrap();
+    /// assert_eq!(
+    ///     config.set_formats(vec![Format::Toml]).is_ok(),
+    ///     cfg!(feature = "with-toml"),
+    /// );
+    /// ```
+    pub fn set_formats(&mut self, formats: Vec<Format>) -> anyhow::Result<()> {
+        if formats.is_empty() {
+            return Err(anyhow::Error::msg("The formats list is empty."));
+        }
+
+        for format in formats.iter() {
+            if !format.is_enabled() {
+                return Err(anyhow::Error::msg(format!(
+                    "You must enable the `with-{}` feature.",
+                    format.get_extension()
+                )));
+            }
+        }
+
+        self.formats = formats;
+        Ok(())
+    }
+
+    /// Get the moment when `load` reads the texts of the languages.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::{Config, LoadingStrategy};
+    ///
+    /// let config = Config::new("examples/texts/json", vec!["en"]).unwrap();
+    /// assert_eq!(config.get_loading_strategy(), LoadingStrategy::Eager);
+    /// ```
+    pub fn get_loading_strategy(&self) -> LoadingStrategy {
+        self.loading_strategy
+    }
+
+    /// Change the moment when `load` reads the texts of the languages.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::{Config, LoadingStrategy};
+    ///
+    /// let mut config = Config::new("examples/texts/json", vec!["en"]).unwrap();
+    /// config.set_loading_strategy(LoadingStrategy::Lazy);
+    /// assert_eq!(config.get_loading_strategy(), LoadingStrategy::Lazy);
+    /// ```
+    pub fn set_loading_strategy(&mut self, loading_strategy: LoadingStrategy) {
+        self.loading_strategy = loading_strategy;
+    }
+
+    /// Normalize a locale like `es_CO.UTF-8` or `en_US@euro` to a language tag like `es-CO`.
+    /// Returns `None` for the `C` and `POSIX` locales.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::Config;
+    ///
+    /// assert_eq!(Config::normalize_locale("es_CO.UTF-8"), Some(String::from("es-CO")));
+    /// assert_eq!(Config::normalize_locale("EN-us"), Some(String::from("en-US")));
+    /// assert_eq!(Config::normalize_locale("de_DE@euro"), Some(String::from("de-DE")));
+    /// assert_eq!(Config::normalize_locale("fr"), Some(String::from("fr")));
+    /// assert_eq!(Config::normalize_locale("C.UTF-8"), None);
+    /// ```
+    pub fn normalize_locale(locale: &str) -> Option<String> {
+        let locale = locale.split(&['.', '@'][..]).next()?.trim();
+        if locale.is_empty() || locale == "C" || locale == "POSIX" {
+            return None;
+        }
+
+        let mut parts = locale.split(&['_', '-'][..]);
+        let mut tag = parts.next()?.to_lowercase();
+        for part in parts {
+            tag.push('-');
+            tag.push_str(&if part.len() == 2 {
+                part.to_uppercase()
+            } else {
+                part.to_string()
+            });
+        }
+
+        Some(tag)
+    }
+
+    /// Get the first language that matches one of the requested locales, in order of preference.
+    /// A locale matches a language with the same tag, like `es-CO`, or with the same primary
+    /// language, like `es`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::Config;
+    ///
+    /// let config = Config::new("examples/texts/json", vec!["en", "es"]).unwrap();
+    /// assert_eq!(
+    ///     config.negotiate_language(&["fr_FR", "es_CO.UTF-8"]),
+    ///     Some(String::from("es")),
+    /// );
+    /// assert_eq!(config.negotiate_language(&["fr"]), None);
+    /// ```
+    pub fn negotiate_language(&self, locales: &[&str]) -> Option<String> {
+        let languages: Vec<(String, Option<String>)> = self
+            .languages
+            .iter()
+            .map(|language| (language.clone(), Self::normalize_locale(language)))
+            .collect();
+
+        let primary = |tag: &str| tag.split('-').next().unwrap_or("").to_string();
+
+        for locale in locales.iter() {
+            let locale = match Self::normalize_locale(locale) {
+                Some(locale) => locale,
+                None => continue,
+            };
+
+            // Check the languages with the same tag first and then with the same primary
+            // language.
+            let exact = languages
+                .iter()
+                .find(|(_, tag)| tag.as_deref() == Some(locale.as_str()));
+            let similar = languages
+                .iter()
+                .find(|(_, tag)| tag.as_deref().map(primary) == Some(primary(&locale)));
+
+            if let Some((language, _)) = exact.or(similar) {
+                return Some(language.clone());
+            }
+        }
+
+        None
+    }
+
+    /// Detect the language of the user from the `LANGUAGE`, `LC_ALL`, `LC_MESSAGES` and `LANG`
+    /// environment variables like `gettext`, where the first of `LC_ALL`, `LC_MESSAGES` and
+    /// `LANG` that is set is the locale and the `LANGUAGE` list goes before it unless the locale
+    /// is `C` or `POSIX`, and negotiate it with the languages. Returns the default language if no
+    /// locale matches.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::env;
+    ///
+    /// use languages_rs::Config;
+    ///
+    /// env::remove_var("LANGUAGE");
+    /// env::remove_var("LC_ALL");
+    /// env::remove_var("LC_MESSAGES");
+    /// env::set_var("LANG", "es_CO.UTF-8");
+    ///
+    /// let mut config = Config::new("examples/texts/json", vec!["en", "es"]).unwrap();
+    /// assert_eq!(config.detect_system_language(), Some(String::from("es")));
+    ///
+    /// env::set_var("LANG", "fr_FR.UTF-8");
+    /// assert_eq!(config.detect_system_language(), None);
+    ///
+    /// // `LC_ALL` overrides `LANG` even if it does not match.
+    /// env::set_var("LC_ALL", "fr_FR.UTF-8");
+    /// env::set_var("LANG", "es_CO.UTF-8");
+    /// assert_eq!(config.detect_system_language(), None);
+    ///
+    /// // `LANGUAGE` is ignored for the `C` locale.
+    /// env::set_var("LC_ALL", "C");
+    /// env::set_var("LANGUAGE", "es");
+    /// assert_eq!(config.detect_system_language(), None);
+    ///
+    /// env::set_var("LC_ALL", "fr_FR.UTF-8");
+    /// assert_eq!(config.detect_system_language(), Some(String::from("es")));
+    /// env::remove_var("LANGUAGE");
+    /// env::remove_var("LC_ALL");
+    /// env::set_var("LANG", "fr_FR.UTF-8");
+    ///
+    /// assert!(config.set_default_language(String::from("en")).is_ok());
+    /// assert_eq!(config.detect_system_language(), Some(String::from("en")));
+    /// ```
+    pub fn detect_system_language(&self) -> Option<String> {
+        // The first of `LC_ALL`, `LC_MESSAGES` and `LANG` that is set overrides the others.
+        let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|name| env::var(name).ok())
+            .find(|locale| !locale.is_empty());
+
+        let mut locales: Vec<String> = Vec::new();
+        if let Some(locale) = locale {
+            // The `LANGUAGE` variable is a list of locales separated by colons, and it is
+            // ignored when the locale is `C` or `POSIX`.
+            let name = locale.split(['.', '@']).next().unwrap_or_default();
+            if name != "C" && name != "POSIX" {
+                if let Ok(language) = env::var("LANGUAGE") {
+                    locales.extend(
+                        language
+                            .split(':')
+                            .filter(|locale| !locale.is_empty())
+                            .map(String::from),
+                    );
+                }
+
+                locales.push(locale);
+            }
+        }
+
+        let locales: Vec<&str> = locales.iter().map(|locale| locale.as_str()).collect();
+        self.negotiate_language(&locales)
+            .or_else(|| self.get_default_language())
+    }
+
+    /// Check if `load` discovers the languages of the sources before loading them.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::Config;
+    ///
+    /// let config = Config::new("examples/texts/json", vec![]).unwrap();
+    /// assert!(!config.is_discover_languages());
+    /// ```
+    pub fn is_discover_languages(&self) -> bool {
+        self.discover_languages
+    }
+
+    /// Change if `load` discovers the languages of the sources before loading them.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::Config;
+    ///
+    /// let mut config = Config::new("examples/texts/json", vec![]).unwrap();
+    /// config.set_discover_languages(true);
+    /// assert!(config.is_discover_languages());
+    /// ```
+    pub fn set_discover_languages(&mut self, discover_languages: bool) {
+        self.discover_languages = discover_languages;
+    }
+
+    /// Add a filter of the languages to discover. When there are include filters, only the
+    /// languages that match one of them are discovered. The `*` character matches any text.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::Config;
+    ///
+    /// let mut config = Config::new("examples/texts/json", vec![]).unwrap();
+    /// config.add_include_filter("e*");
+    /// assert_eq!(config.get_include_filters(), vec![String::from("e*")]);
+    /// ```
+    pub fn add_include_filter(&mut self, pattern: &str) {
+        self.include_filters.push(String::from(pattern));
+    }
+
+    /// Get the filters of the languages to discover.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::Config;
+    ///
+    /// let config = Config::new("examples/texts/json", vec![]).unwrap();
+    /// assert!(config.get_include_filters().is_empty());
+    /// ```
+    pub fn get_include_filters(&self) -> Vec<String> {
+        self.include_filters.clone()
+    }
+
+    /// Add a filter of the languages to ignore when they are discovered. The `*` character
+    /// matches any text.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::Config;
+    ///
+    /// let mut config = Config::new("examples/texts/json", vec![]).unwrap();
+    /// config.add_exclude_filter("es");
+    /// assert_eq!(config.get_exclude_filters(), vec![String::from("es")]);
+    /// ```
+    pub fn add_exclude_filter(&mut self, pattern: &str) {
+        self.exclude_filters.push(String::from(pattern));
+    }
+
+    /// Get the filters of the languages to ignore when they are discovered.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::Config;
+    ///
+    /// let config = Config::new("examples/texts/json", vec![]).unwrap();
+    /// assert!(config.get_exclude_filters().is_empty());
+    /// ```
+    pub fn get_exclude_filters(&self) -> Vec<String> {
+        self.exclude_filters.clone()
+    }
+
+    /// Add the languages found in the sources, with a file of an enabled format or a directory
+    /// of namespace files, that pass the filters. Returns the new languages sorted by name.
+    ///
+    /// The `languages.toml` and `i18n.json` manifests are not languages, so they can be in the
+    /// languages directory.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::Config;
+    ///
+    /// #[cfg(feature = "with-json")]
+    /// fn main() {
+    ///     let mut config = Config::new("examples/texts/json", vec![]).unwrap();
+    ///     config.add_exclude_filter("es");
+    ///
+    ///     let languages = config.discover_languages();
+    ///     assert!(languages.is_ok());
+    ///     assert_eq!(languages.unwrap(), vec![String::from("en")]);
+    ///     assert_eq!(config.get_languages(), vec![String::from("en")]);
+    ///
+    ///     let directory = std::env::temp_dir().join("languages-rs-discover-manifest");
+    ///     std::fs::create_dir_all(&directory).unwrap();
+    ///     std::fs::write(directory.join("en.json"), r#"{ "hi": "Hi" }"#).unwrap();
+    ///     std::fs::write(directory.join("i18n.json"), r#"{ "directory": "." }"#).unwrap();
+    ///
+    ///     let mut config = Config::new(&directory, vec![]).unwrap();
+    ///     assert_eq!(config.discover_languages().unwrap(), vec![String::from("en")]);
+    /// }
+    ///
+    /// #[cfg(not(feature = "with-json"))]
+    /// fn main() {}
+    /// ```
+    pub fn discover_languages(&mut self) -> anyhow::Result<Vec<String>> {
+        let files = SourceFiles::default();
+
+        let mut languages: Vec<String> = Vec::new();
+        for source in self.get_sources().iter() {
+            for language in source.discover_languages(&self.formats, &files)? {
+                if !self.languages.contains(&language)
+                    && !languages.contains(&language)
+                    && self.is_discoverable(&language)
+                {
+                    languages.push(language);
+                }
+            }
+        }
+
+        languages.sort();
+        self.languages.extend(languages.iter().cloned());
+
+        Ok(languages)
+    }
+
+    /// Check if a discovered language passes the include and exclude filters.
+    fn is_discoverable(&self, language: &str) -> bool {
+        (self.include_filters.is_empty()
+            || self
+                .include_filters
+                .iter()
+                .any(|pattern| matches_pattern(pattern, language)))
+            && !self
+                .exclude_filters
+                .iter()
+                .any(|pattern| matches_pattern(pattern, language))
+    }
+}
+
+/// Check if a path exists and it is a directory.
+pub(crate) fn check_directory(path: &Path) -> anyhow::Result<()> {
+    if !path.exists() {
+        return Err(anyhow::Error::msg(format!(
+            "Cannot find `{}` directory.",
+            path.display()
+        )));
+    } else if !path.is_dir() {
+        return Err(anyhow::Error::msg(format!(
+            "The path `{}` is not a directory.",
+            path.display()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Check if a text matches a pattern where `*` matches any text.
+fn matches_pattern(pattern: &str, text: &str) -> bool {
+    match pattern.find('*') {
+        Some(index) => {
+            let (prefix, rest) = (&pattern[..index], &pattern[index + 1..]);
+            text.starts_with(prefix)
+                && (0..=text.len() - prefix.len()).any(|skip| {
+                    text.is_char_boundary(prefix.len() + skip)
+                        && matches_pattern(rest, &text[prefix.len() + skip..])
+                })
+        }
+        None => pattern == text,
+    }
 }
@@ -0,0 +1,268 @@
+use std::path::{Path, PathBuf};
+
+use crate::{
+    config::{check_directory, LoadingStrategy},
+    Config, Format, Source,
+};
+
+/// A builder of configurations that checks every option in `build`.
+#[derive(Clone, Debug)]
+pub struct ConfigBuilder {
+    directory: PathBuf,
+    sources: Vec<Source>,
+    languages: Vec<String>,
+    default_language: Option<String>,
+    fallback_languages: Vec<String>,
+    formats: Vec<Format>,
+    loading_strategy: LoadingStrategy,
+    discover_languages: bool,
+    include_filters: Vec<String>,
+    exclude_filters: Vec<String>,
+}
+
+impl ConfigBuilder {
+    /// Create a new builder with the `languages` directory and the enabled formats.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::ConfigBuilder;
+    ///
+    /// let config = ConfigBuilder::new().directory("examples/texts/json").build();
+    /// assert!(config.is_ok());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            directory: PathBuf::from("languages"),
+            sources: Vec::new(),
+            languages: Vec::new(),
+            default_language: None,
+            fallback_languages: Vec::new(),
+            formats: Vec::new(),
+            loading_strategy: LoadingStrategy::Eager,
+            discover_languages: false,
+            include_filters: Vec::new(),
+            exclude_filters: Vec::new(),
+        }
+    }
+
+    /// Change the languages directory.
+    pub fn directory<P: AsRef<Path>>(mut self, directory: P) -> Self {
+        self.directory = directory.as_ref().to_path_buf();
+        self
+    }
+
+    /// Add a source of the languages texts, overriding the texts of the previous sources.
+    pub fn source(mut self, source: Source) -> Self {
+        self.sources.push(source);
+        self
+    }
+
+    /// Add a language.
+    pub fn language(mut self, language: &str) -> Self {
+        self.languages.push(String::from(language));
+        self
+    }
+
+    /// Add several languages.
+    pub fn languages(mut self, languages: Vec<&str>) -> Self {
+        self.languages
+            .extend(languages.iter().map(|e| String::from(*e)));
+        self
+    }
+
+    /// Change the default language.
+    pub fn default_language(mut self, language: &str) -> Self {
+        self.default_language = Some(String::from(language));
+        self
+    }
+
+    /// Add a language to use when a language does not have a text.
+    pub fn fallback_language(mut self, language: &str) -> Self {
+        self.fallback_languages.push(String::from(language));
+        self
+    }
+
+    /// Add a format of the languages files, in order of preference. The enabled formats are used
+    /// if no format is added.
+    pub fn format(mut self, format: Format) -> Self {
+        self.formats.push(format);
+        self
+    }
+
+    /// Change the moment when `load` reads the texts of the languages.
+    pub fn loading_strategy(mut self, loading_strategy: LoadingStrategy) -> Self {
+        self.loading_strategy = loading_strategy;
+        self
+    }
+
+    /// Change if `load` discovers the languages of the sources before loading them.
+    pub fn discover_languages(mut self, discover_languages: bool) -> Self {
+        self.discover_languages = discover_languages;
+        self
+    }
+
+    /// Add a filter of the languages to discover.
+    pub fn include_filter(mut self, pattern: &str) -> Self {
+        self.include_filters.push(String::from(pattern));
+        self
+    }
+
+    /// Add a filter of the languages to ignore when they are discovered.
+    pub fn exclude_filter(mut self, pattern: &str) -> Self {
+        self.exclude_filters.push(String::from(pattern));
+        self
+    }
+
+    /// Check every option and create the configuration, or return an error with all the
+    /// problems found.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::Config;
+    ///
+    /// let config = Config::builder()
+    ///     .directory("examples/texts/json")
+    ///     .languages(vec!["en", "es", "en"])
+    ///     .default_language("fr")
+    ///     .build();
+    /// assert!(config.is_err());
+    ///
+    /// let error = config.err().unwrap().to_string();
+    /// assert!(error.contains("The language `en` already exists."));
+    /// assert!(error.contains("Cannot find the `fr` default lang."));
+    ///
+    /// // The default language can be a discovered language.
+    /// #[cfg(feature = "with-json")]
+    /// assert!(Config::builder()
+    ///     .directory("examples/texts/json")
+    ///     .discover_languages(true)
+    ///     .default_language("es")
+    ///     .build()
+    ///     .is_ok());
+    /// ```
+    pub fn build(self) -> anyhow::Result<Config> {
+        let mut problems: Vec<String> = Vec::new();
+
+        let is_directory_valid = match check_directory(&self.directory) {
+            Ok(_) => true,
+            Err(e) => {
+                problems.push(e.to_string());
+                false
+            }
+        };
+
+        let mut sources: Vec<Source> = vec![Source::Directory(self.directory.clone())];
+        for source in self.sources.iter() {
+            if sources.contains(source) {
+                problems.push(format!("The source {} already exists.", source));
+                continue;
+            }
+
+            match source {
+                Source::Directory(directory) => {
+                    if let Err(e) = check_directory(directory) {
+                        problems.push(e.to_string());
+                    }
+                }
+                Source::Catalog(path) if !path.is_file() => {
+                    problems.push(format!("Cannot find `{}` catalog.", path.display()));
+                }
+                #[cfg(any(feature = "with-zip", feature = "with-tar"))]
+                Source::Archive(path) if !path.is_file() => {
+                    problems.push(format!("Cannot find `{}` archive.", path.display()));
+                }
+                _ => {}
+            }
+
+            sources.push(source.clone());
+        }
+
+        let mut languages: Vec<String> = Vec::new();
+        for language in self.languages.iter() {
+            if languages.contains(language) {
+                problems.push(format!("The language `{}` already exists.", language));
+            } else {
+                languages.push(language.clone());
+            }
+        }
+
+        let mut formats: Vec<Format> = Vec::new();
+        for format in self.formats.iter() {
+            if !format.is_enabled() {
+                problems.push(format!(
+                    "You must enable the `with-{}` feature.",
+                    format.get_extension()
+                ));
+            } else if !formats.contains(format) {
+                formats.push(*format);
+            }
+        }
+
+        let formats = if self.formats.is_empty() {
+            Format::enabled_formats()
+        } else {
+            formats
+        };
+
+        // The default and fallback languages can be discovered languages.
+        let mut available_languages = languages.clone();
+        if self.discover_languages && is_directory_valid {
+            let mut config = Config::new(&self.directory, vec![])?;
+            config.sources = sources[1..].to_vec();
+            config.formats = formats.clone();
+            config.include_filters = self.include_filters.clone();
+            config.exclude_filters = self.exclude_filters.clone();
+
+            match config.discover_languages() {
+                Ok(discovered) => available_languages.extend(discovered),
+                Err(e) => problems.push(format!("Cannot discover the languages: {}", e)),
+            }
+        }
+
+        if let Some(language) = &self.default_language {
+            if !available_languages.contains(language) {
+                problems.push(format!("Cannot find the `{}` default lang.", language));
+            }
+        }
+
+        let mut fallback_languages: Vec<String> = Vec::new();
+        for language in self.fallback_languages.iter() {
+            if !available_languages.contains(language) {
+                problems.push(format!("Cannot find the `{}` fallback lang.", language));
+            } else if fallback_languages.contains(language) {
+                problems.push(format!(
+                    "The fallback language `{}` already exists.",
+                    language
+                ));
+            } else {
+                fallback_languages.push(language.clone());
+            }
+        }
+
+        if !problems.is_empty() {
+            return Err(anyhow::Error::msg(format!(
+                "The configuration is not valid:\n- {}",
+                problems.join("\n- ")
+            )));
+        }
+
+        Ok(Config {
+            directory: self.directory,
+            sources: sources.split_off(1),
+            languages,
+            default_language: self.default_language,
+            fallback_languages,
+            formats,
+            loading_strategy: self.loading_strategy,
+            discover_languages: self.discover_languages,
+            include_filters: self.include_filters,
+            exclude_filters: self.exclude_filters,
+        })
+    }
+}
+
+impl Default for ConfigBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
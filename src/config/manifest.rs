@@ -0,0 +1,226 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::{config::LoadingStrategy, ConfigBuilder, Format, Source};
+
+/// The file names of the manifests, which are not languages when they are in a languages
+/// directory.
+pub(crate) const MANIFEST_FILES: [&str; 2] = ["languages.toml", "i18n.json"];
+
+/// The prefix of the environment variables that override the options of a manifest.
+const ENV_PREFIX: &str = "LANGUAGES_RS_";
+
+/// The options of a `languages.toml` or `i18n.json` manifest. Every option is optional.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct Manifest {
+    directory: Option<String>,
+    sources: Vec<String>,
+    languages: Vec<String>,
+    default_language: Option<String>,
+    fallback_languages: Vec<String>,
+    formats: Vec<String>,
+    loading_strategy: Option<String>,
+    discover_languages: Option<bool>,
+    include_filters: Vec<String>,
+    exclude_filters: Vec<String>,
+}
+
+impl Manifest {
+    /// Read a manifest with the format of its extension.
+    pub(crate) fn read(path: &Path) -> anyhow::Result<Self> {
+        let format = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .and_then(Format::from_extension)
+            .ok_or_else(|| {
+                anyhow::Error::msg(format!(
+                    "The manifest `{}` must be a JSON or TOML file.",
+                    path.display()
+                ))
+            })?;
+
+        if !format.is_enabled() {
+            return Err(anyhow::Error::msg(format!(
+                "You must enable the `with-{}` feature.",
+                format.get_extension()
+            )));
+        }
+
+        let content = fs::read_to_string(path).map_err(|e| {
+            anyhow::Error::msg(format!(
+                "Cannot read the manifest `{}`: {}",
+                path.display(),
+                e
+            ))
+        })?;
+
+        Self::parse(format, &content).map_err(|e| {
+            anyhow::Error::msg(format!(
+                "Cannot parse the manifest `{}`: {}",
+                path.display(),
+                e
+            ))
+        })
+    }
+
+    fn parse(format: Format, content: &str) -> anyhow::Result<Self> {
+        match format {
+            #[cfg(feature = "with-json")]
+            Format::Json => Ok(serde_json::from_str(content)?),
+            #[cfg(feature = "with-toml")]
+            Format::Toml => Ok(toml::from_str(content)?),
+            #[allow(unreachable_patterns)]
+            _ => {
+                let _ = content;
+                Err(anyhow::Error::msg(format!(
+                    "You must enable the `with-{}` feature.",
+                    format.get_extension()
+                )))
+            }
+        }
+    }
+
+    /// Replace the options that have an environment variable, like `LANGUAGES_RS_LANGUAGES`.
+    /// The lists are separated by commas.
+    pub(crate) fn apply_env_overrides(&mut self) -> anyhow::Result<()> {
+        if let Some(directory) = get_env("DIRECTORY") {
+            self.directory = Some(directory);
+        }
+
+        if let Some(sources) = get_env_list("SOURCES") {
+            self.sources = sources;
+        }
+
+        if let Some(languages) = get_env_list("LANGUAGES") {
+            self.languages = languages;
+        }
+
+        if let Some(default_language) = get_env("DEFAULT_LANGUAGE") {
+            self.default_language = Some(default_language);
+        }
+
+        if let Some(fallback_languages) = get_env_list("FALLBACK_LANGUAGES") {
+            self.fallback_languages = fallback_languages;
+        }
+
+        if let Some(formats) = get_env_list("FORMATS") {
+            self.formats = formats;
+        }
+
+        if let Some(loading_strategy) = get_env("LOADING_STRATEGY") {
+            self.loading_strategy = Some(loading_strategy);
+        }
+
+        if let Some(discover_languages) = get_env("DISCOVER_LANGUAGES") {
+            self.discover_languages = Some(match discover_languages.as_str() {
+                "true" | "1" => true,
+                "false" | "0" => false,
+                _ => {
+                    return Err(anyhow::Error::msg(format!(
+                        "The variable `{}DISCOVER_LANGUAGES` must be `true` or `false`.",
+                        ENV_PREFIX
+                    )))
+                }
+            });
+        }
+
+        if let Some(include_filters) = get_env_list("INCLUDE_FILTERS") {
+            self.include_filters = include_filters;
+        }
+
+        if let Some(exclude_filters) = get_env_list("EXCLUDE_FILTERS") {
+            self.exclude_filters = exclude_filters;
+        }
+
+        Ok(())
+    }
+
+    /// Create a builder with the options of the manifest. The relative directories are joined to
+    /// the base directory, which is the directory of the manifest.
+    pub(crate) fn into_builder(self, base: &Path) -> anyhow::Result<ConfigBuilder> {
+        let resolve = |directory: &str| -> PathBuf {
+            let path = PathBuf::from(directory);
+            if path.is_absolute() {
+                path
+            } else {
+                base.join(path)
+            }
+        };
+
+        let mut builder = ConfigBuilder::new().directory(match &self.directory {
+            Some(directory) => resolve(directory),
+            None => base.join("languages"),
+        });
+
+        for source in self.sources.iter() {
+            builder = builder.source(Source::Directory(resolve(source)));
+        }
+
+        for language in self.languages.iter() {
+            builder = builder.language(language);
+        }
+
+        if let Some(default_language) = &self.default_language {
+            builder = builder.default_language(default_language);
+        }
+
+        for language in self.fallback_languages.iter() {
+            builder = builder.fallback_language(language);
+        }
+
+        for format in self.formats.iter() {
+            builder = builder.format(Format::from_extension(format).ok_or_else(|| {
+                anyhow::Error::msg(format!("The format `{}` is not supported.", format))
+            })?);
+        }
+
+        if let Some(loading_strategy) = &self.loading_strategy {
+            builder = builder.loading_strategy(match loading_strategy.as_str() {
+                "eager" => LoadingStrategy::Eager,
+                "lazy" => LoadingStrategy::Lazy,
+                _ => {
+                    return Err(anyhow::Error::msg(format!(
+                        "The loading strategy `{}` is not supported.",
+                        loading_strategy
+                    )))
+                }
+            });
+        }
+
+        if let Some(discover_languages) = self.discover_languages {
+            builder = builder.discover_languages(discover_languages);
+        }
+
+        for pattern in self.include_filters.iter() {
+            builder = builder.include_filter(pattern);
+        }
+
+        for pattern in self.exclude_filters.iter() {
+            builder = builder.exclude_filter(pattern);
+        }
+
+        Ok(builder)
+    }
+}
+
+fn get_env(name: &str) -> Option<String> {
+    env::var(format!("{}{}", ENV_PREFIX, name))
+        .ok()
+        .map(|value| value.trim().to_string())
+}
+
+fn get_env_list(name: &str) -> Option<Vec<String>> {
+    get_env(name).map(|value| {
+        value
+            .split(',')
+            .map(|item| item.trim())
+            .filter(|item| !item.is_empty())
+            .map(String::from)
+            .collect()
+    })
+}
@@ -0,0 +1,322 @@
+use std::fmt;
+
+use crate::{value::PathSegment, LanguageTexts, Value};
+
+/// A problem of the texts of a language compared with the reference language.
+#[derive(Clone, Debug, PartialEq)]
+pub enum IssueKind {
+    /// The reference language has the text but the language does not.
+    MissingKey,
+    /// The language has a text that the reference language does not have.
+    ExtraKey,
+    /// The text is a different type, like an array instead of a string.
+    TypeMismatch { expected: String, found: String },
+    /// The array has a different number of texts.
+    ArrayLength { expected: usize, found: usize },
+    /// The text is an empty string.
+    EmptyString,
+    /// The text does not have the same placeholders, like `{name}`, as the reference text.
+    PlaceholderMismatch {
+        missing: Vec<String>,
+        extra: Vec<String>,
+    },
+}
+
+/// A problem of a text of a language, with the full path of the text.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConsistencyIssue {
+    language: String,
+    path: String,
+    kind: IssueKind,
+}
+
+impl ConsistencyIssue {
+    /// Get the language of the text.
+    pub fn get_language(&self) -> String {
+        self.language.clone()
+    }
+
+    /// Get the full path of the text, like `pages.home.title` or `messages[0]`.
+    pub fn get_path(&self) -> String {
+        self.path.clone()
+    }
+
+    /// Get the problem of the text.
+    pub fn get_kind(&self) -> IssueKind {
+        self.kind.clone()
+    }
+}
+
+impl fmt::Display for ConsistencyIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] `{}`: ", self.language, self.path)?;
+
+        match &self.kind {
+            IssueKind::MissingKey => write!(f, "missing key"),
+            IssueKind::ExtraKey => write!(f, "extra key"),
+            IssueKind::TypeMismatch { expected, found } => {
+                write!(f, "expected {} but found {}", expected, found)
+            }
+            IssueKind::ArrayLength { expected, found } => {
+                write!(f, "expected {} items but found {}", expected, found)
+            }
+            IssueKind::EmptyString => write!(f, "empty string"),
+            IssueKind::PlaceholderMismatch { missing, extra } => {
+                let mut problems = Vec::new();
+                if !missing.is_empty() {
+                    problems.push(format!("missing placeholders {{{}}}", missing.join("}, {")));
+                }
+
+                if !extra.is_empty() {
+                    problems.push(format!("unknown placeholders {{{}}}", extra.join("}, {")));
+                }
+
+                write!(f, "{}", problems.join(" and "))
+            }
+        }
+    }
+}
+
+/// The problems of the texts of several languages compared with a reference language.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConsistencyReport {
+    reference: String,
+    issues: Vec<ConsistencyIssue>,
+}
+
+impl ConsistencyReport {
+    /// Compare the texts of several languages with the texts of a reference language, looking
+    /// for missing keys, extra keys, type mismatches, array length differences, empty strings and
+    /// placeholders that are different from the reference texts.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::{ConsistencyReport, IssueKind, LanguageTexts, Value};
+    ///
+    /// let mut data = HashMap::new();
+    /// data.insert(String::from("hi"), Value::String(String::from("Hi")));
+    /// data.insert(String::from("bye"), Value::String(String::from("Bye")));
+    /// let texts_en = LanguageTexts::new(String::from("en"), Value::Object(data)).unwrap();
+    ///
+    /// let mut data = HashMap::new();
+    /// data.insert(String::from("hi"), Value::String(String::new()));
+    /// data.insert(String::from("welcome"), Value::String(String::from("Bienvenido")));
+    /// let texts_es = LanguageTexts::new(String::from("es"), Value::Object(data)).unwrap();
+    ///
+    /// let report = ConsistencyReport::compare(&texts_en, &[texts_es]);
+    /// assert!(!report.is_consistent());
+    ///
+    /// let issues = report.get_issues();
+    /// assert_eq!(issues.len(), 3);
+    /// assert_eq!(issues[0].get_path(), "bye");
+    /// assert_eq!(issues[0].get_kind(), IssueKind::MissingKey);
+    /// assert_eq!(issues[1].get_kind(), IssueKind::EmptyString);
+    /// assert_eq!(issues[2].get_kind(), IssueKind::ExtraKey);
+    ///
+    /// let mut data = HashMap::new();
+    /// data.insert(String::from("hi"), Value::String(String::from("Hola {nombre}")));
+    /// data.insert(String::from("bye"), Value::String(String::from("Adiós")));
+    /// let texts_es = LanguageTexts::new(String::from("es"), Value::Object(data)).unwrap();
+    ///
+    /// let mut data = HashMap::new();
+    /// data.insert(String::from("hi"), Value::String(String::from("Hi {name}")));
+    /// data.insert(String::from("bye"), Value::String(String::from("Bye")));
+    /// let texts_en = LanguageTexts::new(String::from("en"), Value::Object(data)).unwrap();
+    ///
+    /// let issues = ConsistencyReport::compare(&texts_en, &[texts_es]).get_issues();
+    /// assert_eq!(issues.len(), 1);
+    /// assert_eq!(issues[0].to_string(), "[es] `hi`: missing placeholders {name} and unknown placeholders {nombre}");
+    /// ```
+    pub fn compare(reference: &LanguageTexts, languages: &[LanguageTexts]) -> Self {
+        let mut issues = Vec::new();
+
+        // The empty strings of the reference language are problems too.
+        for (path, text) in reference.get_texts().flatten() {
+            if text.trim().is_empty() {
+                issues.push(ConsistencyIssue {
+                    language: reference.get_language(),
+                    path,
+                    kind: IssueKind::EmptyString,
+                });
+            }
+        }
+
+        for lang_texts in languages.iter() {
+            if lang_texts.get_language() == reference.get_language() {
+                continue;
+            }
+
+            compare_values(
+                &lang_texts.get_language(),
+                &reference.get_texts(),
+                &lang_texts.get_texts(),
+                &mut Vec::new(),
+                &mut issues,
+            );
+        }
+
+        Self {
+            reference: reference.get_language(),
+            issues,
+        }
+    }
+
+    /// Get the reference language of the report.
+    pub fn get_reference_language(&self) -> String {
+        self.reference.clone()
+    }
+
+    /// Get all the problems found.
+    pub fn get_issues(&self) -> Vec<ConsistencyIssue> {
+        self.issues.clone()
+    }
+
+    /// Get the problems of a specific language.
+    pub fn get_language_issues(&self, lang: &str) -> Vec<ConsistencyIssue> {
+        self.issues
+            .iter()
+            .filter(|issue| issue.language == lang)
+            .cloned()
+            .collect()
+    }
+
+    /// Check if no problems were found.
+    pub fn is_consistent(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl fmt::Display for ConsistencyReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.issues.is_empty() {
+            return write!(
+                f,
+                "All the languages are consistent with `{}`.",
+                self.reference
+            );
+        }
+
+        write!(
+            f,
+            "Found {} problems compared with `{}`:",
+            self.issues.len(),
+            self.reference
+        )?;
+
+        for issue in self.issues.iter() {
+            write!(f, "\n- {}", issue)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Get the name of the type of a value for the reports.
+fn get_type_name(value: &Value) -> String {
+    String::from(match value {
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    })
+}
+
+fn compare_values(
+    language: &str,
+    reference: &Value,
+    value: &Value,
+    path: &mut Vec<PathSegment>,
+    issues: &mut Vec<ConsistencyIssue>,
+) {
+    match (reference, value) {
+        (Value::String(_), Value::String(text)) => {
+            if text.trim().is_empty() {
+                push_issue(issues, language, path, IssueKind::EmptyString);
+                return;
+            }
+
+            let expected = reference.get_placeholders();
+            let found = value.get_placeholders();
+            if expected != found {
+                push_issue(
+                    issues,
+                    language,
+                    path,
+                    IssueKind::PlaceholderMismatch {
+                        missing: expected
+                            .iter()
+                            .filter(|name| !found.contains(name))
+                            .cloned()
+                            .collect(),
+                        extra: found
+                            .iter()
+                            .filter(|name| !expected.contains(name))
+                            .cloned()
+                            .collect(),
+                    },
+                );
+            }
+        }
+        (Value::Array(expected), Value::Array(found)) => {
+            if expected.len() != found.len() {
+                push_issue(
+                    issues,
+                    language,
+                    path,
+                    IssueKind::ArrayLength {
+                        expected: expected.len(),
+                        found: found.len(),
+                    },
+                );
+            }
+
+            for (index, (expected, found)) in expected.iter().zip(found.iter()).enumerate() {
+                path.push(PathSegment::Index(index));
+                compare_values(language, expected, found, path, issues);
+                path.pop();
+            }
+        }
+        (Value::Object(expected), Value::Object(found)) => {
+            let mut keys: Vec<&String> = expected.keys().chain(found.keys()).collect();
+            keys.sort();
+            keys.dedup();
+
+            for key in keys {
+                path.push(PathSegment::Key(key.clone()));
+
+                match (expected.get(key), found.get(key)) {
+                    (Some(expected), Some(found)) => {
+                        compare_values(language, expected, found, path, issues)
+                    }
+                    (Some(_), None) => push_issue(issues, language, path, IssueKind::MissingKey),
+                    _ => push_issue(issues, language, path, IssueKind::ExtraKey),
+                }
+
+                path.pop();
+            }
+        }
+        (expected, found) => push_issue(
+            issues,
+            language,
+            path,
+            IssueKind::TypeMismatch {
+                expected: get_type_name(expected),
+                found: get_type_name(found),
+            },
+        ),
+    }
+}
+
+fn push_issue(
+    issues: &mut Vec<ConsistencyIssue>,
+    language: &str,
+    path: &[PathSegment],
+    kind: IssueKind,
+) {
+    issues.push(ConsistencyIssue {
+        language: String::from(language),
+        path: PathSegment::join(path),
+        kind,
+    });
+}
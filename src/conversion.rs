@@ -0,0 +1,403 @@
+use std::fmt;
+
+#[cfg(feature = "with-json")]
+use serde_json::Value as JsonValue;
+#[cfg(feature = "with-toml")]
+use toml::Value as TomlValue;
+
+#[cfg(any(feature = "with-json", feature = "with-toml"))]
+use crate::value::PathSegment;
+use crate::Format;
+
+/// The result of converting a languages file from a format to another.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Conversion {
+    text: String,
+    lossy_values: Vec<LossyValue>,
+}
+
+impl Conversion {
+    /// Get the text written in the target format.
+    pub fn get_text(&self) -> String {
+        self.text.clone()
+    }
+
+    /// Get the values that were changed or removed because the target format cannot represent
+    /// them.
+    pub fn get_lossy_values(&self) -> Vec<LossyValue> {
+        self.lossy_values.clone()
+    }
+
+    /// Check if every value was written without changes.
+    pub fn is_lossless(&self) -> bool {
+        self.lossy_values.is_empty()
+    }
+}
+
+/// A value that cannot be represented in the target format of a conversion.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LossyValue {
+    path: String,
+    reason: String,
+}
+
+impl LossyValue {
+    /// Get the full path of the value, like `pages.home.title` or `messages[0]`.
+    pub fn get_path(&self) -> String {
+        self.path.clone()
+    }
+
+    /// Get what happened with the value.
+    pub fn get_reason(&self) -> String {
+        self.reason.clone()
+    }
+}
+
+impl fmt::Display for LossyValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}`: {}", self.path, self.reason)
+    }
+}
+
+/// A value of a languages file that keeps the order of the keys and the types that `Value` does
+/// not support, so they can be reported. Some types are only read by one of the formats.
+#[cfg(any(feature = "with-json", feature = "with-toml"))]
+enum Node {
+    String(String),
+    Integer(i64),
+    /// An integer greater than `i64::MAX`.
+    #[cfg(feature = "with-json")]
+    BigInteger(u64),
+    Float(f64),
+    Boolean(bool),
+    #[cfg(feature = "with-toml")]
+    Datetime(String),
+    #[cfg(feature = "with-json")]
+    Null,
+    Array(Vec<Node>),
+    Table(Vec<(String, Node)>),
+}
+
+#[cfg(any(feature = "with-json", feature = "with-toml"))]
+impl Node {
+    /// Describe the values that are not texts, arrays or objects, which the languages cannot
+    /// read.
+    fn describe_scalar(&self) -> Option<String> {
+        match self {
+            Self::String(_) | Self::Array(_) | Self::Table(_) => None,
+            Self::Integer(number) => Some(format!("the integer `{}`", number)),
+            #[cfg(feature = "with-json")]
+            Self::BigInteger(number) => Some(format!("the integer `{}`", number)),
+            Self::Float(number) => Some(format!("the float `{}`", number)),
+            Self::Boolean(value) => Some(format!("the boolean `{}`", value)),
+            #[cfg(feature = "with-toml")]
+            Self::Datetime(date) => Some(format!("the date `{}`", date)),
+            #[cfg(feature = "with-json")]
+            Self::Null => Some(String::from("the null value")),
+        }
+    }
+}
+
+/// Convert the text of a languages file from a format to another.
+#[cfg(any(feature = "with-json", feature = "with-toml"))]
+pub(crate) fn convert(text: String, source: Format, target: Format) -> anyhow::Result<Conversion> {
+    let node = parse(text, source)?;
+
+    let mut lossy_values = Vec::new();
+    let text = write(&node, target, &mut lossy_values)?;
+
+    // The values that are not texts are written, but the languages cannot read them.
+    report_scalars(&node, &mut Vec::new(), &mut lossy_values);
+
+    Ok(Conversion { text, lossy_values })
+}
+
+#[cfg(not(any(feature = "with-json", feature = "with-toml")))]
+pub(crate) fn convert(
+    _text: String,
+    source: Format,
+    _target: Format,
+) -> anyhow::Result<Conversion> {
+    Err(source.disabled_error())
+}
+
+/// Report the values that are not texts, arrays or objects, unless the target format already
+/// reported them.
+#[cfg(any(feature = "with-json", feature = "with-toml"))]
+fn report_scalars(node: &Node, path: &mut Vec<PathSegment>, lossy_values: &mut Vec<LossyValue>) {
+    match node {
+        Node::Array(values) => {
+            for (index, value) in values.iter().enumerate() {
+                path.push(PathSegment::Index(index));
+                report_scalars(value, path, lossy_values);
+                path.pop();
+            }
+        }
+        Node::Table(data) => {
+            for (key, value) in data.iter() {
+                path.push(PathSegment::Key(key.clone()));
+                report_scalars(value, path, lossy_values);
+                path.pop();
+            }
+        }
+        _ => {
+            let joined = PathSegment::join(path);
+            if let Some(description) = node.describe_scalar() {
+                if !lossy_values
+                    .iter()
+                    .any(|lossy_value| lossy_value.path == joined)
+                {
+                    push_lossy_value(
+                        lossy_values,
+                        path,
+                        format!(
+                            "{} is not a text, so the languages cannot read it",
+                            description
+                        ),
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(any(feature = "with-json", feature = "with-toml"))]
+fn parse(text: String, format: Format) -> anyhow::Result<Node> {
+    match format {
+        #[cfg(feature = "with-json")]
+        Format::Json => Ok(from_json_value(serde_json::from_str(&text)?)),
+        #[cfg(feature = "with-toml")]
+        Format::Toml => Ok(from_toml_value(toml::from_str(&text)?)),
+        #[allow(unreachable_patterns)]
+        _ => {
+            let _ = text;
+            Err(format.disabled_error())
+        }
+    }
+}
+
+#[cfg(any(feature = "with-json", feature = "with-toml"))]
+fn write(
+    node: &Node,
+    format: Format,
+    lossy_values: &mut Vec<LossyValue>,
+) -> anyhow::Result<String> {
+    match format {
+        #[cfg(feature = "with-json")]
+        Format::Json => Ok(serde_json::to_string_pretty(&to_json_value(
+            node,
+            &mut Vec::new(),
+            lossy_values,
+        ))?),
+        #[cfg(feature = "with-toml")]
+        Format::Toml => {
+            if !matches!(node, Node::Table(_)) {
+                return Err(anyhow::Error::msg(
+                    "Cannot write the texts as TOML, they are not an object.",
+                ));
+            }
+
+            let value = to_toml_value(node, &mut Vec::new(), lossy_values)?
+                .unwrap_or_else(|| TomlValue::Table(toml::value::Table::new()));
+
+            Ok(toml::to_string_pretty(&value)?)
+        }
+        #[allow(unreachable_patterns)]
+        _ => {
+            let _ = (node, lossy_values);
+            Err(format.disabled_error())
+        }
+    }
+}
+
+#[cfg(any(feature = "with-json", feature = "with-toml"))]
+fn push_lossy_value(lossy_values: &mut Vec<LossyValue>, path: &[PathSegment], reason: String) {
+    lossy_values.push(LossyValue {
+        path: PathSegment::join(path),
+        reason,
+    });
+}
+
+#[cfg(feature = "with-json")]
+fn from_json_value(value: JsonValue) -> Node {
+    match value {
+        JsonValue::String(text) => Node::String(text),
+        JsonValue::Number(number) => match (number.as_i64(), number.as_u64()) {
+            (Some(number), _) => Node::Integer(number),
+            (None, Some(number)) => Node::BigInteger(number),
+            _ => Node::Float(number.as_f64().unwrap_or(f64::NAN)),
+        },
+        JsonValue::Bool(value) => Node::Boolean(value),
+        JsonValue::Null => Node::Null,
+        JsonValue::Array(values) => Node::Array(values.into_iter().map(from_json_value).collect()),
+        JsonValue::Object(data) => Node::Table(
+            data.into_iter()
+                .map(|(key, value)| (key, from_json_value(value)))
+                .collect(),
+        ),
+    }
+}
+
+#[cfg(feature = "with-json")]
+fn to_json_value(
+    node: &Node,
+    path: &mut Vec<PathSegment>,
+    lossy_values: &mut Vec<LossyValue>,
+) -> JsonValue {
+    match node {
+        Node::String(text) => JsonValue::String(text.clone()),
+        Node::Integer(number) => JsonValue::from(*number),
+        Node::BigInteger(number) => JsonValue::from(*number),
+        Node::Float(number) => match serde_json::Number::from_f64(*number) {
+            Some(number) => JsonValue::Number(number),
+            None => {
+                push_lossy_value(
+                    lossy_values,
+                    path,
+                    format!("the float `{}` is written as null", number),
+                );
+
+                JsonValue::Null
+            }
+        },
+        Node::Boolean(value) => JsonValue::Bool(*value),
+        #[cfg(feature = "with-toml")]
+        Node::Datetime(date) => {
+            push_lossy_value(
+                lossy_values,
+                path,
+                format!("the date `{}` is written as a string", date),
+            );
+
+            JsonValue::String(date.clone())
+        }
+        Node::Null => JsonValue::Null,
+        Node::Array(values) => JsonValue::Array(
+            values
+                .iter()
+                .enumerate()
+                .map(|(index, value)| {
+                    path.push(PathSegment::Index(index));
+                    let value = to_json_value(value, path, lossy_values);
+                    path.pop();
+
+                    value
+                })
+                .collect(),
+        ),
+        Node::Table(data) => {
+            let mut new_data = serde_json::Map::new();
+            for (key, value) in data.iter() {
+                path.push(PathSegment::Key(key.clone()));
+                new_data.insert(key.clone(), to_json_value(value, path, lossy_values));
+                path.pop();
+            }
+
+            JsonValue::Object(new_data)
+        }
+    }
+}
+
+#[cfg(feature = "with-toml")]
+fn from_toml_value(value: TomlValue) -> Node {
+    match value {
+        TomlValue::String(text) => Node::String(text),
+        TomlValue::Integer(number) => Node::Integer(number),
+        TomlValue::Float(number) => Node::Float(number),
+        TomlValue::Boolean(value) => Node::Boolean(value),
+        TomlValue::Datetime(date) => Node::Datetime(date.to_string()),
+        TomlValue::Array(values) => Node::Array(values.into_iter().map(from_toml_value).collect()),
+        TomlValue::Table(data) => Node::Table(
+            data.into_iter()
+                .map(|(key, value)| (key, from_toml_value(value)))
+                .collect(),
+        ),
+    }
+}
+
+/// Get the TOML value of a node, or `None` if TOML cannot represent it and it is removed.
+#[cfg(feature = "with-toml")]
+#[cfg_attr(not(feature = "with-json"), allow(clippy::only_used_in_recursion))]
+fn to_toml_value(
+    node: &Node,
+    path: &mut Vec<PathSegment>,
+    lossy_values: &mut Vec<LossyValue>,
+) -> anyhow::Result<Option<TomlValue>> {
+    let value = match node {
+        Node::String(text) => TomlValue::String(text.clone()),
+        Node::Integer(number) => TomlValue::Integer(*number),
+        #[cfg(feature = "with-json")]
+        Node::BigInteger(number) => {
+            push_lossy_value(
+                lossy_values,
+                path,
+                format!(
+                    "the integer `{}` is too big and is written as a float",
+                    number
+                ),
+            );
+
+            TomlValue::Float(*number as f64)
+        }
+        Node::Float(number) => TomlValue::Float(*number),
+        Node::Boolean(value) => TomlValue::Boolean(*value),
+        #[cfg(feature = "with-toml")]
+        Node::Datetime(date) => match date.parse() {
+            Ok(date) => TomlValue::Datetime(date),
+            Err(_) => TomlValue::String(date.clone()),
+        },
+        #[cfg(feature = "with-json")]
+        Node::Null => {
+            push_lossy_value(
+                lossy_values,
+                path,
+                String::from("TOML does not have null values, it is removed"),
+            );
+
+            return Ok(None);
+        }
+        Node::Array(values) => {
+            let mut new_values = Vec::new();
+            for (index, value) in values.iter().enumerate() {
+                path.push(PathSegment::Index(index));
+                if let Some(value) = to_toml_value(value, path, lossy_values)? {
+                    new_values.push(value);
+                }
+
+                path.pop();
+            }
+
+            // TOML arrays must have values of the same type.
+            if let Some(first) = new_values.first() {
+                if let Some(other) = new_values
+                    .iter()
+                    .find(|value| value.type_str() != first.type_str())
+                {
+                    return Err(anyhow::Error::msg(format!(
+                        "Cannot write `{}` as TOML, the array mixes {} and {} values.",
+                        PathSegment::join(path),
+                        first.type_str(),
+                        other.type_str()
+                    )));
+                }
+            }
+
+            TomlValue::Array(new_values)
+        }
+        Node::Table(data) => {
+            let mut new_data = toml::value::Table::new();
+            for (key, value) in data.iter() {
+                path.push(PathSegment::Key(key.clone()));
+                if let Some(value) = to_toml_value(value, path, lossy_values)? {
+                    new_data.insert(key.clone(), value);
+                }
+
+                path.pop();
+            }
+
+            TomlValue::Table(new_data)
+        }
+    };
+
+    Ok(Some(value))
+}
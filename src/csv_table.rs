@@ -0,0 +1,489 @@
+use std::{collections::HashMap, fmt, fs, path::Path};
+
+use crate::{source::write_atomic, value::PathSegment, LanguageTexts, Value};
+
+/// The name of the first column of the CSV files, with the text paths.
+const KEY_COLUMN: &str = "key";
+
+/// A row of a CSV file with its line, to report the problems of the row.
+#[derive(Clone, Debug, PartialEq)]
+struct CsvRow {
+    line: usize,
+    fields: Vec<String>,
+}
+
+/// A problem of a row of a CSV file found when it is merged into the texts.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CsvProblemKind {
+    /// The row does not have a field for every column.
+    WrongFieldCount { expected: usize, found: usize },
+    /// The row does not have a text path.
+    EmptyKey,
+    /// The text path is not valid, like `home..title`.
+    InvalidKey,
+    /// The text path is in a previous row too, which is the one that is used.
+    DuplicateKey { first_line: usize },
+    /// The text cannot be written in the texts of a language, like a text path that is an
+    /// object or an array in the texts.
+    Conflict { language: String, reason: String },
+    /// The column of a language is not in the merged texts, so it is not merged. The problem is
+    /// in the line of the header and does not have a text path.
+    UnknownLanguage { language: String },
+}
+
+/// A problem of a row of a CSV file, with its line and text path.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsvProblem {
+    line: usize,
+    key: String,
+    kind: CsvProblemKind,
+}
+
+impl CsvProblem {
+    /// Get the line of the row, starting at 1 with the header.
+    pub fn get_line(&self) -> usize {
+        self.line
+    }
+
+    /// Get the text path of the row.
+    pub fn get_key(&self) -> String {
+        self.key.clone()
+    }
+
+    /// Get the problem of the row.
+    pub fn get_kind(&self) -> CsvProblemKind {
+        self.kind.clone()
+    }
+}
+
+impl fmt::Display for CsvProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+
+        match &self.kind {
+            CsvProblemKind::WrongFieldCount { expected, found } => {
+                write!(f, "expected {} fields but found {}", expected, found)
+            }
+            CsvProblemKind::EmptyKey => write!(f, "empty key"),
+            CsvProblemKind::InvalidKey => write!(f, "`{}` is not a valid text path", self.key),
+            CsvProblemKind::DuplicateKey { first_line } => {
+                write!(f, "`{}` is already in line {}", self.key, first_line)
+            }
+            CsvProblemKind::Conflict { language, reason } => {
+                write!(f, "[{}] `{}`: {}", language, self.key, reason)
+            }
+            CsvProblemKind::UnknownLanguage { language } => {
+                write!(
+                    f,
+                    "the `{}` column is not a language of the texts",
+                    language
+                )
+            }
+        }
+    }
+}
+
+/// A CSV file with a row for every text path and a column for every language, to edit the texts
+/// in a spreadsheet.
+///
+/// The first column is `key` with the text paths, like `pages.home.title` or `messages[0]`, and
+/// the other columns have the names of the languages.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsvTable {
+    languages: Vec<String>,
+    rows: Vec<CsvRow>,
+}
+
+impl CsvTable {
+    /// Create a CSV table with the strings of several languages, with the text paths of all the
+    /// languages sorted and empty fields for the texts that a language does not have. Returns an
+    /// error if the texts have empty keys or empty objects and arrays, which have no rows.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::{CsvTable, LanguageTexts, Value};
+    ///
+    /// let mut home = HashMap::new();
+    /// home.insert(String::from("title"), Value::String(String::from("Home, sweet home")));
+    ///
+    /// let mut data = HashMap::new();
+    /// data.insert(String::from("home"), Value::Object(home));
+    /// data.insert(String::from("hi"), Value::String(String::from("Hi")));
+    /// let texts_en = LanguageTexts::new(String::from("en"), Value::Object(data)).unwrap();
+    ///
+    /// let mut data = HashMap::new();
+    /// data.insert(String::from("hi"), Value::String(String::from("Hola")));
+    /// let texts_es = LanguageTexts::new(String::from("es"), Value::Object(data)).unwrap();
+    ///
+    /// let table = CsvTable::from_languages(&[texts_en, texts_es]).unwrap();
+    /// assert_eq!(
+    ///     table.to_csv_string().unwrap(),
+    ///     "key,en,es\nhi,Hi,Hola\nhome.title,\"Home, sweet home\",\n"
+    /// );
+    ///
+    /// // The empty arrays and objects do not have rows, so they cannot be written.
+    /// let mut data = HashMap::new();
+    /// data.insert(String::from("home"), Value::Object(HashMap::new()));
+    /// let texts_fr = LanguageTexts::new(String::from("fr"), Value::Object(data)).unwrap();
+    /// assert!(CsvTable::from_languages(&[texts_fr]).is_err());
+    /// ```
+    pub fn from_languages(languages: &[LanguageTexts]) -> anyhow::Result<Self> {
+        let texts: Vec<HashMap<String, String>> = languages
+            .iter()
+            .map(|lang_texts| {
+                let texts = lang_texts.get_texts().try_flatten().map_err(|e| {
+                    anyhow::Error::msg(format!(
+                        "Cannot write the texts of the `{}` lang in the CSV table: {}",
+                        lang_texts.get_language(),
+                        e
+                    ))
+                })?;
+
+                Ok(texts.into_iter().collect())
+            })
+            .collect::<anyhow::Result<Vec<HashMap<String, String>>>>()?;
+
+        let mut paths: Vec<Vec<PathSegment>> = Vec::new();
+        for (lang_texts, language) in texts.iter().zip(languages.iter()) {
+            for key in lang_texts.keys() {
+                paths.push(PathSegment::parse(key).map_err(|e| {
+                    anyhow::Error::msg(format!(
+                        "Cannot write the text `{}` of the `{}` lang in the CSV table: {}",
+                        key,
+                        language.get_language(),
+                        e
+                    ))
+                })?);
+            }
+        }
+        paths.sort();
+        paths.dedup();
+
+        let rows = paths
+            .iter()
+            .enumerate()
+            .map(|(index, path)| {
+                let key = PathSegment::join(path);
+
+                let mut fields = vec![key.clone()];
+                for lang_texts in texts.iter() {
+                    fields.push(lang_texts.get(&key).cloned().unwrap_or_default());
+                }
+
+                CsvRow {
+                    line: index + 2,
+                    fields,
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            languages: languages
+                .iter()
+                .map(|lang_texts| lang_texts.get_language())
+                .collect(),
+            rows,
+        })
+    }
+
+    /// Read a CSV table from a string. The rows with problems are kept and reported when the
+    /// table is merged into the texts.
+    pub fn from_csv_string(text: &str) -> anyhow::Result<Self> {
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .from_reader(text.as_bytes());
+
+        let mut records = reader.records();
+
+        let header = match records.next() {
+            Some(header) => header?,
+            None => return Err(anyhow::Error::msg("The CSV file does not have a header.")),
+        };
+
+        // The spreadsheets can write a byte order mark before the header.
+        let first_column = header
+            .get(0)
+            .map(|column| column.trim_start_matches('\u{feff}').trim());
+
+        if first_column != Some(KEY_COLUMN) {
+            return Err(anyhow::Error::msg(format!(
+                "The first column of the CSV file must be `{}`.",
+                KEY_COLUMN
+            )));
+        }
+
+        let languages: Vec<String> = header
+            .iter()
+            .skip(1)
+            .map(|column| String::from(column.trim()))
+            .collect();
+
+        for (i, language) in languages.iter().enumerate() {
+            if language.is_empty() || languages[..i].contains(language) {
+                return Err(anyhow::Error::msg(format!(
+                    "The column `{}` of the CSV file is empty or duplicated.",
+                    language
+                )));
+            }
+        }
+
+        let mut rows = Vec::new();
+        for record in records {
+            let record = record?;
+
+            // Skip the empty lines.
+            if record.iter().all(|field| field.trim().is_empty()) {
+                continue;
+            }
+
+            rows.push(CsvRow {
+                line: record
+                    .position()
+                    .map(|position| position.line() as usize)
+                    .unwrap_or_default(),
+                fields: record.iter().map(String::from).collect(),
+            });
+        }
+
+        Ok(Self { languages, rows })
+    }
+
+    /// Write the CSV table as a string.
+    pub fn to_csv_string(&self) -> anyhow::Result<String> {
+        let mut writer = csv::WriterBuilder::new()
+            .flexible(true)
+            .from_writer(Vec::new());
+
+        let mut header = vec![String::from(KEY_COLUMN)];
+        header.extend(self.languages.iter().cloned());
+        writer.write_record(&header)?;
+
+        for row in self.rows.iter() {
+            writer.write_record(&row.fields)?;
+        }
+
+        Ok(String::from_utf8(writer.into_inner()?)?)
+    }
+
+    /// Read a CSV file.
+    pub fn read<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)
+            .map_err(|e| anyhow::Error::msg(format!("Cannot read `{}`: {}", path.display(), e)))?;
+
+        Self::from_csv_string(&text).map_err(|e| {
+            anyhow::Error::msg(format!("Cannot parse `{}` file: {}", path.display(), e))
+        })
+    }
+
+    /// Write the CSV table to a file atomically.
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> anyhow::Result<()> {
+        write_atomic(path.as_ref(), self.to_csv_string()?)
+    }
+
+    /// Get the languages of the columns.
+    pub fn get_languages(&self) -> Vec<String> {
+        self.languages.clone()
+    }
+
+    /// Merge the texts of the CSV table into the texts of the languages that have a column,
+    /// returning the rows that are malformed or conflict with the texts and the columns of the
+    /// languages that are not in the texts.
+    ///
+    /// The empty fields are not merged, so they do not remove texts, and the rows with problems
+    /// are skipped.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::{CsvProblemKind, CsvTable, LanguageTexts, Value};
+    ///
+    /// let mut home = HashMap::new();
+    /// home.insert(String::from("title"), Value::String(String::from("Inicio")));
+    ///
+    /// let mut data = HashMap::new();
+    /// data.insert(String::from("home"), Value::Object(home));
+    /// let mut texts_es = LanguageTexts::new(String::from("es"), Value::Object(data)).unwrap();
+    ///
+    /// let table = CsvTable::from_csv_string(
+    ///     "key,en,es
+    /// hi,Hi,Hola
+    /// bye,Bye
+    /// home,Home,Inicio
+    /// hi,Hello,Hola
+    /// home.title,Home page,Página de inicio
+    /// ",
+    /// )
+    /// .unwrap();
+    ///
+    /// let problems = table.merge_into(std::slice::from_mut(&mut texts_es));
+    /// assert_eq!(problems.len(), 4);
+    /// assert_eq!(
+    ///     problems[0].to_string(),
+    ///     "line 1: the `en` column is not a language of the texts"
+    /// );
+    /// assert_eq!(
+    ///     problems[1].get_kind(),
+    ///     CsvProblemKind::WrongFieldCount { expected: 3, found: 2 }
+    /// );
+    /// assert_eq!(
+    ///     problems[2].to_string(),
+    ///     "line 4: [es] `home`: the text is an object in the language"
+    /// );
+    /// assert_eq!(problems[3].get_kind(), CsvProblemKind::DuplicateKey { first_line: 2 });
+    ///
+    /// assert!(texts_es.is_dirty());
+    /// assert_eq!(texts_es.try_get_path("hi"), Some(Value::String(String::from("Hola"))));
+    /// assert_eq!(
+    ///     texts_es.try_get_path("home.title"),
+    ///     Some(Value::String(String::from("Página de inicio")))
+    /// );
+    /// ```
+    pub fn merge_into(&self, languages: &mut [LanguageTexts]) -> Vec<CsvProblem> {
+        let mut problems = Vec::new();
+
+        // Check the rows before merging them into the texts of every language.
+        let mut rows: Vec<(&CsvRow, String, Vec<PathSegment>)> = Vec::new();
+        for row in self.rows.iter() {
+            let key = String::from(row.fields[0].trim());
+            let mut push_problem = |kind| {
+                problems.push(CsvProblem {
+                    line: row.line,
+                    key: key.clone(),
+                    kind,
+                })
+            };
+
+            if row.fields.len() != self.languages.len() + 1 {
+                push_problem(CsvProblemKind::WrongFieldCount {
+                    expected: self.languages.len() + 1,
+                    found: row.fields.len(),
+                });
+                continue;
+            }
+
+            if key.is_empty() {
+                push_problem(CsvProblemKind::EmptyKey);
+                continue;
+            }
+
+            let path = match PathSegment::parse(&key) {
+                Ok(path) => path,
+                Err(_) => {
+                    push_problem(CsvProblemKind::InvalidKey);
+                    continue;
+                }
+            };
+
+            if let Some((first_row, _, _)) = rows.iter().find(|(_, other, _)| *other == key) {
+                push_problem(CsvProblemKind::DuplicateKey {
+                    first_line: first_row.line,
+                });
+                continue;
+            }
+
+            rows.push((row, key, path));
+        }
+
+        for (column, language) in self.languages.iter().enumerate() {
+            let lang_texts = match languages
+                .iter_mut()
+                .find(|lang_texts| lang_texts.get_language() == *language)
+            {
+                Some(lang_texts) => lang_texts,
+                None => {
+                    problems.push(CsvProblem {
+                        line: 1,
+                        key: String::new(),
+                        kind: CsvProblemKind::UnknownLanguage {
+                            language: language.clone(),
+                        },
+                    });
+                    continue;
+                }
+            };
+
+            let mut texts = lang_texts.get_texts();
+            for (row, key, path) in rows.iter() {
+                let text = &row.fields[column + 1];
+                if text.is_empty() {
+                    continue;
+                }
+
+                let text = Value::String(text.clone());
+                let result = match check_insert(&texts, path) {
+                    Ok(Some(current)) if current == text => Ok(()),
+                    Ok(_) => texts
+                        .insert_path(path, text.clone())
+                        .and_then(|_| lang_texts.insert_text(key, text))
+                        .map(|_| ())
+                        .map_err(|e| e.to_string()),
+                    Err(reason) => Err(reason),
+                };
+
+                if let Err(reason) = result {
+                    problems.push(CsvProblem {
+                        line: row.line,
+                        key: key.clone(),
+                        kind: CsvProblemKind::Conflict {
+                            language: language.clone(),
+                            reason,
+                        },
+                    });
+                }
+            }
+        }
+
+        problems.sort_by_key(|problem| problem.line);
+        problems
+    }
+}
+
+/// Check if a text can be written at a path without replacing an object or an array, returning
+/// the current text of the path.
+fn check_insert(texts: &Value, path: &[PathSegment]) -> Result<Option<Value>, String> {
+    let mut current = Some(texts);
+    for segment in path.iter() {
+        current = match (current, segment) {
+            (Some(Value::Object(data)), PathSegment::Key(key)) => data.get(key),
+            (Some(Value::Array(values)), PathSegment::Index(index)) if *index <= values.len() => {
+                values.get(*index)
+            }
+            (Some(Value::Array(values)), PathSegment::Index(index)) => {
+                return Err(format!(
+                    "the index {} is after the end of an array of {} texts",
+                    index,
+                    values.len()
+                ))
+            }
+            // The missing arrays are created empty, so only their first index can be used.
+            (None, PathSegment::Index(index)) if *index > 0 => {
+                return Err(format!(
+                    "the index {} is after the end of a new array",
+                    index
+                ))
+            }
+            (None, _) => None,
+            (Some(Value::String(_)), _) => {
+                return Err(String::from(
+                    "a parent of the text is a text in the language",
+                ))
+            }
+            (Some(_), _) => {
+                return Err(String::from(
+                    "a parent of the text is a different type in the language",
+                ))
+            }
+        };
+    }
+
+    match current {
+        Some(Value::Object(_)) => Err(String::from("the text is an object in the language")),
+        Some(Value::Array(_)) => Err(String::from("the text is an array in the language")),
+        current => Ok(current.cloned()),
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{conversion, Conversion, Value};
+
+/// The formats of the languages files.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Toml,
+}
+
+impl Format {
+    /// Get the formats enabled with the `with-json` and `with-toml` features.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::Format;
+    ///
+    /// #[cfg(feature = "with-json")]
+    /// assert!(Format::enabled_formats().contains(&Format::Json));
+    ///
+    /// #[cfg(not(feature = "with-toml"))]
+    /// assert!(!Format::enabled_formats().contains(&Format::Toml));
+    /// ```
+    pub fn enabled_formats() -> Vec<Self> {
+        [Self::Json, Self::Toml]
+            .iter()
+            .copied()
+            .filter(|format| format.is_enabled())
+            .collect()
+    }
+
+    /// Get the format of a file extension.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::Format;
+    ///
+    /// assert_eq!(Format::from_extension("json"), Some(Format::Json));
+    /// assert_eq!(Format::from_extension("toml"), Some(Format::Toml));
+    /// assert_eq!(Format::from_extension("yaml"), None);
+    /// ```
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension {
+            "json" => Some(Self::Json),
+            "toml" => Some(Self::Toml),
+            _ => None,
+        }
+    }
+
+    /// Get the file extension of the format.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::Format;
+    ///
+    /// assert_eq!(Format::Json.get_extension(), "json");
+    /// assert_eq!(Format::Toml.get_extension(), "toml");
+    /// ```
+    pub fn get_extension(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Toml => "toml",
+        }
+    }
+
+    /// Check if the Cargo feature of the format is enabled.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::Format;
+    ///
+    /// assert_eq!(Format::Json.is_enabled(), cfg!(feature = "with-json"));
+    /// assert_eq!(Format::Toml.is_enabled(), cfg!(feature = "with-toml"));
+    /// ```
+    pub fn is_enabled(&self) -> bool {
+        match self {
+            Self::Json => cfg!(feature = "with-json"),
+            Self::Toml => cfg!(feature = "with-toml"),
+        }
+    }
+
+    /// Parse the texts of a string written in the format.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::{Format, Value};
+    ///
+    /// #[cfg(feature = "with-json")]
+    /// fn main() {
+    ///     let value = Format::Json.parse(String::from("\"Hi\""));
+    ///     assert!(value.is_ok());
+    ///     assert_eq!(value.unwrap(), Value::String(String::from("Hi")));
+    /// }
+    ///
+    /// #[cfg(not(feature = "with-json"))]
+    /// fn main() {
+    ///     assert!(Format::Json.parse(String::from("\"Hi\"")).is_err());
+    /// }
+    /// ```
+    pub fn parse(&self, text: String) -> anyhow::Result<Value> {
+        match self {
+            #[cfg(feature = "with-json")]
+            Self::Json => Value::from_json_string(text),
+            #[cfg(feature = "with-toml")]
+            Self::Toml => Value::from_toml_string(text),
+            #[allow(unreachable_patterns)]
+            _ => {
+                let _ = text;
+                Err(self.disabled_error())
+            }
+        }
+    }
+
+    /// Write the texts as a string in the format.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::{Format, Value};
+    ///
+    /// #[cfg(feature = "with-json")]
+    /// fn main() {
+    ///     let text = Format::Json.serialize(&Value::String(String::from("Hi")));
+    ///     assert!(text.is_ok());
+    ///     assert_eq!(text.unwrap(), "\"Hi\"");
+    /// }
+    ///
+    /// #[cfg(not(feature = "with-json"))]
+    /// fn main() {
+    ///     assert!(Format::Json.serialize(&Value::String(String::from("Hi"))).is_err());
+    /// }
+    /// ```
+    pub fn serialize(&self, value: &Value) -> anyhow::Result<String> {
+        match self {
+            #[cfg(feature = "with-json")]
+            Self::Json => value.to_json_string(),
+            #[cfg(feature = "with-toml")]
+            Self::Toml => value.to_toml_string(),
+            #[allow(unreachable_patterns)]
+            _ => {
+                let _ = value;
+                Err(self.disabled_error())
+            }
+        }
+    }
+
+    /// Convert the text of a languages file written in the format to another format, keeping the
+    /// order of the keys. The values that the target format cannot represent, like the `null`
+    /// values in TOML or the dates in JSON, are reported as lossy values, like the numbers and
+    /// booleans that the languages cannot read because they are not texts.
+    ///
+    /// TOML writes the texts before the objects of each object, so those keys can move.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::Format;
+    ///
+    /// #[cfg(all(feature = "with-json", feature = "with-toml"))]
+    /// fn main() {
+    ///     let text = String::from("title = \"Home\"\ndescription = \"The home page\"\n");
+    ///
+    ///     let conversion = Format::Toml.convert(text, Format::Json).unwrap();
+    ///     assert!(conversion.is_lossless());
+    ///     assert_eq!(
+    ///         conversion.get_text(),
+    ///         "{\n  \"title\": \"Home\",\n  \"description\": \"The home page\"\n}"
+    ///     );
+    ///
+    ///     let text = String::from("{ \"title\": \"Home\", \"subtitle\": null }");
+    ///
+    ///     let conversion = Format::Json.convert(text, Format::Toml).unwrap();
+    ///     assert_eq!(conversion.get_text(), "title = 'Home'\n");
+    ///
+    ///     let lossy_values = conversion.get_lossy_values();
+    ///     assert_eq!(lossy_values.len(), 1);
+    ///     assert_eq!(
+    ///         lossy_values[0].to_string(),
+    ///         "`subtitle`: TOML does not have null values, it is removed"
+    ///     );
+    ///
+    ///     let text = String::from("{ \"title\": \"Home\", \"count\": 5 }");
+    ///
+    ///     let conversion = Format::Json.convert(text, Format::Toml).unwrap();
+    ///     assert_eq!(
+    ///         conversion.get_lossy_values()[0].to_string(),
+    ///         "`count`: the integer `5` is not a text, so the languages cannot read it"
+    ///     );
+    /// }
+    ///
+    /// #[cfg(not(all(feature = "with-json", feature = "with-toml")))]
+    /// fn main() {
+    ///     let text = String::from("title = \"Home\"");
+    ///     assert!(Format::Toml.convert(text, Format::Json).is_err());
+    /// }
+    /// ```
+    pub fn convert(&self, text: String, target: Format) -> anyhow::Result<Conversion> {
+        conversion::convert(text, *self, target)
+    }
+
+    pub(crate) fn disabled_error(&self) -> anyhow::Error {
+        anyhow::Error::msg(format!(
+            "You must enable the `with-{}` feature.",
+            self.get_extension()
+        ))
+    }
+}
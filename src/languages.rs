@@ -2,14 +2,15 @@ mod language_texts;
 
 pub use language_texts::LanguageTexts;
 
-use std::{fs::read_to_string, path::Path};
+use std::collections::HashMap;
 
-use crate::{Config, Value};
+use crate::{source::SourceFiles, Config, ConsistencyReport, PseudoLocale, Source, Value};
 
 #[derive(Clone)]
 pub struct Languages {
     config: Config,
     langs: Vec<LanguageTexts>,
+    files: SourceFiles,
 }
 
 impl Languages {
@@ -26,6 +27,7 @@ impl Languages {
         Self {
             config: config.clone(),
             langs: Vec::new(),
+            files: SourceFiles::default(),
         }
     }
 
@@ -47,56 +49,207 @@ impl Languages {
     /// ```
     pub fn try_get_language(&mut self, lang: &str) -> anyhow::Result<LanguageTexts> {
         // Check if the configuration has the lang.
-        if !self.config.get_languages().contains(&String::from(lang)) {
-            return Err(anyhow::Error::msg(format!(
-                "Cannot find the `{}` lang.",
-                lang
-            )));
-        }
+        self.check_language(lang)?;
 
-        // Check if the language is in the cache and return it if exists.
-        for lang_texts in self.langs.iter() {
-            if lang_texts.get_language() == *lang {
-                return Ok(lang_texts.clone());
+        // Check if the language is in the cache and return it if it is fully loaded.
+        if let Some(index) = self.get_cached_index(lang) {
+            if !self.langs[index].is_partial() {
+                return Ok(self.langs[index].clone());
             }
         }
 
-        // Get the language texts file location.
-        let path = Path::new(&self.config.get_directory()).join(format!(
-            "{}{}",
-            lang,
-            if cfg!(feature = "with-json") {
-                ".json"
-            } else if cfg!(feature = "with-toml") {
-                ".toml"
-            } else {
-                ""
+        let lang_texts = self.read_language(lang)?;
+        Ok(self.cache_language(lang_texts))
+    }
+
+    /// Get all texts of a specific language like `try_get_language`, but reading the files in a
+    /// blocking thread of Tokio to not stall the async runtime. Requires the `with-async` feature.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::{Config, Languages};
+    ///
+    /// #[cfg(all(feature = "with-async", feature = "with-json"))]
+    /// fn main() {
+    ///     let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    ///     runtime.block_on(async {
+    ///         let config = Config::new("examples/texts/json", vec!["en"]).unwrap();
+    ///         let mut texts = Languages::new(&config);
+    ///
+    ///         let texts_en = texts.try_get_language_async("en").await;
+    ///         assert!(texts_en.is_ok());
+    ///         assert_eq!(texts_en.unwrap().get_language(), String::from("en"));
+    ///     });
+    /// }
+    ///
+    /// #[cfg(not(all(feature = "with-async", feature = "with-json")))]
+    /// fn main() {}
+    /// ```
+    #[cfg(feature = "with-async")]
+    pub async fn try_get_language_async(&mut self, lang: &str) -> anyhow::Result<LanguageTexts> {
+        // Check if the configuration has the lang.
+        self.check_language(lang)?;
+
+        // Check if the language is in the cache and return it if it is fully loaded.
+        if let Some(index) = self.get_cached_index(lang) {
+            if !self.langs[index].is_partial() {
+                return Ok(self.langs[index].clone());
             }
-        ));
+        }
 
-        // Check if the file exists.
-        if !path.exists() {
-            return Err(anyhow::Error::msg(format!(
-                "Cannot find `{}` file.",
-                path.display()
-            )));
-        } else if !path.is_file() {
+        // Share the opened files of the sources with the blocking task.
+        let languages = Self {
+            config: self.config.clone(),
+            langs: Vec::new(),
+            files: self.files.clone(),
+        };
+        let lang = String::from(lang);
+        let lang_texts =
+            tokio::task::spawn_blocking(move || languages.read_language(&lang)).await??;
+
+        Ok(self.cache_language(lang_texts))
+    }
+
+    /// Read the texts of a language again from every source, like the files of an `HttpSource`
+    /// that changed in the server. Returns an error if the texts have changes that were not
+    /// saved, use `Languages::discard_language` first to discard them.
+    ///
+    /// # Example
+    /// ```rust, ignore
+    /// use languages_rs::{Config, Languages};
+    ///
+    /// let mut config = Config::default().unwrap();
+    /// assert!(config.add_language(String::from("en")).is_ok());
+    ///
+    /// let mut texts = Languages::new(&config);
+    /// assert!(texts.try_get_language("en").is_ok());
+    ///
+    /// // This reads `languages/en.json` again.
+    /// assert!(texts.refresh_language("en").is_ok());
+    /// ```
+    pub fn refresh_language(&mut self, lang: &str) -> anyhow::Result<LanguageTexts> {
+        if self.get_dirty_languages().iter().any(|dirty| dirty == lang) {
             return Err(anyhow::Error::msg(format!(
-                "The path `{}` is not a file.",
-                path.display()
+                "The `{}` lang has changes that were not saved.",
+                lang
             )));
         }
 
-        // Generate the language texts object for the file.
-        let lang_texts = LanguageTexts::new(
-            String::from(lang),
-            Value::from_string(read_to_string(path)?)?,
-        )?;
+        let lang_texts = self.read_language(lang)?;
+        if let Some(index) = self.get_cached_index(lang) {
+            self.langs.remove(index);
+        }
 
-        // Add the language texts to the cache.
-        self.langs.push(lang_texts.clone());
+        Ok(self.cache_language(lang_texts))
+    }
 
-        Ok(lang_texts)
+    /// Discard the texts of a language in memory, including the changes that were not saved,
+    /// so they are read again from the sources the next time they are used.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::{Config, Languages, Value};
+    ///
+    /// #[cfg(feature = "with-json")]
+    /// fn main() {
+    ///     let config = Config::new("examples/texts/json", vec!["en"]).unwrap();
+    ///
+    ///     let mut texts = Languages::new(&config);
+    ///     assert!(texts
+    ///         .insert_text_to_language("en", "new", Value::String(String::from("New")))
+    ///         .is_ok());
+    ///
+    ///     // The changes that were not saved are not replaced.
+    ///     assert!(texts.refresh_language("en").is_err());
+    ///
+    ///     assert!(texts.discard_language("en").is_ok());
+    ///     assert!(texts.get_dirty_languages().is_empty());
+    ///     assert!(texts.refresh_language("en").is_ok());
+    ///     assert_eq!(texts.try_get_text_from_language("en", "new").unwrap(), None);
+    /// }
+    ///
+    /// #[cfg(not(feature = "with-json"))]
+    /// fn main() {}
+    /// ```
+    pub fn discard_language(&mut self, lang: &str) -> anyhow::Result<()> {
+        self.check_language(lang)?;
+
+        if let Some(index) = self.get_cached_index(lang) {
+            self.langs.remove(index);
+        }
+
+        Ok(())
+    }
+
+    /// Get the texts of a namespace of a specific language, loading only its file when the
+    /// language is a directory like `languages/en/checkout.json`.
+    ///
+    /// # Example
+    /// ```rust, ignore
+    /// use languages_rs::{Config, Languages};
+    ///
+    /// let mut config = Config::default().unwrap();
+    /// assert!(config.add_language(String::from("en")).is_ok());
+    ///
+    /// let mut texts = Languages::new(&config);
+    ///
+    /// // This only loads `languages/en/checkout.json`.
+    /// let checkout = texts.try_get_namespace("en", "checkout");
+    /// assert!(checkout.is_ok());
+    /// assert!(checkout.unwrap().is_object());
+    /// ```
+    pub fn try_get_namespace(&mut self, lang: &str, namespace: &str) -> anyhow::Result<Value> {
+        match self.load_namespace(lang, namespace)? {
+            Some(value) => Ok(value),
+            None => Err(anyhow::Error::msg(format!(
+                "Cannot find the `{}` namespace of the `{}` lang in {}.",
+                namespace,
+                lang,
+                self.get_sources_names()
+            ))),
+        }
+    }
+
+    /// Get all texts of the default language of the configuration.
+    ///
+    /// # Example
+    /// ```rust, ignore
+    /// use languages_rs::{Config, Languages};
+    ///
+    /// let mut config = Config::default().unwrap();
+    /// assert!(config.add_language(String::from("en")).is_ok());
+    /// assert!(config.set_default_language(String::from("en")).is_ok());
+    ///
+    /// let mut texts = Languages::new(&config);
+    ///
+    /// let texts_en = texts.try_get_default_language();
+    /// assert!(texts_en.is_ok());
+    /// assert_eq!(texts_en.unwrap().get_language(), String::from("en"));
+    /// ```
+    pub fn try_get_default_language(&mut self) -> anyhow::Result<LanguageTexts> {
+        let lang = self.get_default_language()?;
+        self.try_get_language(&lang)
+    }
+
+    /// Get a text of the default language of the configuration.
+    ///
+    /// # Example
+    /// ```rust, ignore
+    /// use languages_rs::{Config, Languages};
+    ///
+    /// let mut config = Config::default().unwrap();
+    /// assert!(config.add_language(String::from("en")).is_ok());
+    /// assert!(config.set_default_language(String::from("en")).is_ok());
+    ///
+    /// let mut texts = Languages::new(&config);
+    ///
+    /// let message = texts.try_get_text("message");
+    /// assert!(message.is_ok());
+    /// assert_eq!(message.unwrap(), Some(Value::String(String::from("Hi"))));
+    /// ```
+    pub fn try_get_text(&mut self, text: &str) -> anyhow::Result<Option<Value>> {
+        let lang = self.get_default_language()?;
+        self.try_get_text_from_language(&lang, text)
     }
 
     /// Get a text of a specific language.
@@ -114,11 +267,635 @@ impl Languages {
     /// assert!(message.is_ok());
     /// assert_eq!(message.unwrap(), Some(Value::String(String::from("Hi"))));
     /// ```
+    ///
+    /// # Example with fallback languages
+    /// ```rust, ignore
+    /// use languages_rs::{Config, Languages};
+    ///
+    /// let config = Config::builder()
+    ///     .directory("languages")
+    ///     .languages(vec!["en", "es"])
+    ///     .fallback_language("en")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let mut texts = Languages::new(&config);
+    ///
+    /// // `languages/es.json` does not have the `message` text.
+    /// let message = texts.try_get_text_from_language("es", "message");
+    /// assert!(message.is_ok());
+    /// assert_eq!(message.unwrap(), Some(Value::String(String::from("Hi"))));
+    /// ```
+    ///
+    /// # Example with namespaces
+    /// ```rust
+    /// use languages_rs::{Config, Languages};
+    ///
+    /// #[cfg(feature = "with-json")]
+    /// fn main() {
+    ///     let config = Config::new("examples/texts/namespaces", vec!["en", "es"]).unwrap();
+    ///     let mut texts = Languages::new(&config);
+    ///
+    ///     // This only loads `examples/texts/namespaces/en/common.json`.
+    ///     let greeting = texts.try_get_text_from_language("en", "common:greeting");
+    ///     assert!(greeting.unwrap().is_some());
+    ///
+    ///     // A missing namespace is a missing text.
+    ///     let missing = texts.try_get_text_from_language("en", "missing:key");
+    ///     assert_eq!(missing.unwrap(), None);
+    /// }
+    ///
+    /// #[cfg(not(feature = "with-json"))]
+    /// fn main() {}
+    /// ```
     pub fn try_get_text_from_language(
         &mut self,
         lang: &str,
         text: &str,
     ) -> anyhow::Result<Option<Value>> {
+        if let Some(value) = self.try_get_own_text(lang, text)? {
+            return Ok(Some(value));
+        }
+
+        // Use the fallback languages if the language does not have the text.
+        for fallback in self.config.get_fallback_languages() {
+            if fallback != lang {
+                if let Some(value) = self.try_get_own_text(&fallback, text)? {
+                    return Ok(Some(value));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Write the texts of a specific language to its file in the languages directory.
+    ///
+    /// The texts are written to a temporary file first and then renamed over the language file,
+    /// so readers never see a partially written file.
+    ///
+    /// Only the texts of the languages directory and the texts changed in memory are written, so
+    /// the texts of the overrides directories and other sources stay in their sources.
+    ///
+    /// # Example
+    /// ```rust, ignore
+    /// use languages_rs::{Config, Languages};
+    ///
+    /// let mut config = Config::default().unwrap();
+    /// assert!(config.add_language(String::from("en")).is_ok());
+    ///
+    /// let mut texts = Languages::new(&config);
+    ///
+    /// // This writes `languages/en.json` again with sorted keys.
+    /// assert!(texts.save_language("en").is_ok());
+    /// ```
+    ///
+    /// # Example with overrides
+    /// ```rust
+    /// use languages_rs::{Config, Languages, Value};
+    ///
+    /// #[cfg(feature = "with-json")]
+    /// fn main() {
+    ///     let directory = std::env::temp_dir().join("languages-rs-save-language-overrides");
+    ///     std::fs::create_dir_all(directory.join("base")).unwrap();
+    ///     std::fs::create_dir_all(directory.join("overrides")).unwrap();
+    ///     std::fs::write(directory.join("base/en.json"), r#"{ "title": "Base", "bye": "Bye" }"#).unwrap();
+    ///     std::fs::write(directory.join("overrides/en.json"), r#"{ "title": "Override" }"#).unwrap();
+    ///
+    ///     let mut config = Config::new(directory.join("base"), vec!["en"]).unwrap();
+    ///     assert!(config.add_directory(directory.join("overrides")).is_ok());
+    ///
+    ///     let mut texts = Languages::new(&config);
+    ///     assert!(texts
+    ///         .insert_text_to_language("en", "hi", Value::String(String::from("Hi")))
+    ///         .is_ok());
+    ///     assert!(texts.save_language("en").is_ok());
+    ///
+    ///     // The base file keeps its own title and gets the new text.
+    ///     let saved = std::fs::read_to_string(directory.join("base/en.json")).unwrap();
+    ///     let saved = Value::from_json_string(saved).unwrap().get_object().unwrap();
+    ///     assert_eq!(saved["title"], Value::String(String::from("Base")));
+    ///     assert_eq!(saved["hi"], Value::String(String::from("Hi")));
+    /// }
+    ///
+    /// #[cfg(not(feature = "with-json"))]
+    /// fn main() {}
+    /// ```
+    ///
+    /// # Example with namespaces
+    /// ```rust
+    /// use languages_rs::{Config, Languages};
+    ///
+    /// #[cfg(feature = "with-json")]
+    /// fn main() {
+    ///     let directory = std::env::temp_dir().join("languages-rs-save-language-namespaces");
+    ///     std::fs::create_dir_all(directory.join("en")).unwrap();
+    ///     for namespace in ["checkout", "common"] {
+    ///         std::fs::copy(
+    ///             format!("examples/texts/namespaces/en/{}.json", namespace),
+    ///             directory.join(format!("en/{}.json", namespace)),
+    ///         )
+    ///         .unwrap();
+    ///     }
+    ///
+    ///     let config = Config::new(&directory, vec!["en"]).unwrap();
+    ///
+    ///     let mut texts = Languages::new(&config);
+    ///     assert!(texts.remove_text_from_language("en", "checkout").is_ok());
+    ///     assert!(texts.save_language("en").is_ok());
+    ///
+    ///     // The file of the removed namespace is removed too.
+    ///     assert!(!directory.join("en/checkout.json").exists());
+    ///     assert!(directory.join("en/common.json").exists());
+    ///
+    ///     let mut texts = Languages::new(&config);
+    ///     assert!(texts.try_get_namespace("en", "checkout").is_err());
+    /// }
+    ///
+    /// #[cfg(not(feature = "with-json"))]
+    /// fn main() {}
+    /// ```
+    pub fn save_language(&mut self, lang: &str) -> anyhow::Result<()> {
+        let source = Source::Directory(self.config.get_directory());
+        let formats = self.config.get_formats();
+        let lang_texts = self.try_get_language(lang)?;
+
+        // Keep the texts of the other sources, like the overrides directories, out of the
+        // languages directory.
+        let original = source.read_language(lang, &formats, &self.files)?;
+        let texts = lang_texts.get_source_texts(&source, original.as_ref());
+        source.write_language(lang, &texts, &formats)?;
+
+        self.get_language_mut(lang)?.set_dirty(false);
+
+        Ok(())
+    }
+
+    /// Write the merged texts of a specific language to a source, like a directory or a SQLite
+    /// database.
+    ///
+    /// # Example
+    /// ```rust, ignore
+    /// use languages_rs::{Config, Languages, Source};
+    ///
+    /// let mut config = Config::default().unwrap();
+    /// assert!(config.add_language(String::from("en")).is_ok());
+    ///
+    /// let mut texts = Languages::new(&config);
+    ///
+    /// // This writes `backup/en.json` with the texts of `languages/en.json`.
+    /// assert!(texts.save_language_to_source("en", &Source::Directory("backup".into())).is_ok());
+    /// ```
+    pub fn save_language_to_source(&mut self, lang: &str, source: &Source) -> anyhow::Result<()> {
+        let lang_texts = self.try_get_language(lang)?;
+        source.write_language(lang, &lang_texts.get_texts(), &self.config.get_formats())?;
+
+        self.get_language_mut(lang)?.set_dirty(false);
+
+        Ok(())
+    }
+
+    /// Add a new language from texts in memory, without a file in the languages directory.
+    ///
+    /// The language is marked as dirty until it is saved with `save_language`.
+    ///
+    /// # Example
+    /// ```rust, ignore
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::{Config, LanguageTexts, Languages, Value};
+    ///
+    /// let config = Config::default().unwrap();
+    /// let mut texts = Languages::new(&config);
+    ///
+    /// let texts_fr = LanguageTexts::new(String::from("fr"), Value::Object(HashMap::new())).unwrap();
+    /// assert!(texts.add_language(texts_fr).is_ok());
+    /// assert_eq!(texts.get_dirty_languages(), vec![String::from("fr")]);
+    /// ```
+    pub fn add_language(&mut self, mut lang_texts: LanguageTexts) -> anyhow::Result<()> {
+        self.config.add_language(lang_texts.get_language())?;
+
+        lang_texts.set_dirty(true);
+        self.langs.push(lang_texts);
+
+        Ok(())
+    }
+
+    /// Add a pseudo-locale, like `en-XA`, with the texts of its reference language rewritten.
+    /// If the pseudo-locale already exists, its texts are created again.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::{Config, Languages, PseudoLocale, Value};
+    ///
+    /// #[cfg(feature = "with-json")]
+    /// fn main() {
+    ///     let config = Config::new("examples/texts/json", vec!["en"]).unwrap();
+    ///     let mut texts = Languages::new(&config);
+    ///
+    ///     let texts_pseudo = texts.add_pseudo_language(&PseudoLocale::new("en-XA", "en"));
+    ///     assert!(texts_pseudo.is_ok());
+    ///     assert_eq!(
+    ///         texts_pseudo.unwrap().try_get_text("greeting"),
+    ///         Some(Value::String(String::from("[Ĥéļļö, ŵöŕļð!~~~~]")))
+    ///     );
+    ///
+    ///     assert!(texts.get_dirty_languages().is_empty());
+    ///     assert!(texts.try_get_text_from_language("en-XA", "greeting").unwrap().is_some());
+    /// }
+    ///
+    /// #[cfg(not(feature = "with-json"))]
+    /// fn main() {}
+    /// ```
+    pub fn add_pseudo_language(
+        &mut self,
+        pseudo_locale: &PseudoLocale,
+    ) -> anyhow::Result<LanguageTexts> {
+        let lang = pseudo_locale.get_language();
+        let reference = self.try_get_language(&pseudo_locale.get_reference_language())?;
+
+        let lang_texts =
+            LanguageTexts::new(lang.clone(), pseudo_locale.localize(&reference.get_texts()))?;
+
+        if !self.config.get_languages().contains(&lang) {
+            self.config.add_language(lang.clone())?;
+        }
+
+        if let Some(index) = self.get_cached_index(&lang) {
+            self.langs.remove(index);
+        }
+
+        self.langs.push(lang_texts.clone());
+        Ok(lang_texts)
+    }
+
+    /// Insert a text value at a path of a specific language.
+    ///
+    /// # Example
+    /// ```rust, ignore
+    /// use languages_rs::{Config, Languages, Value};
+    ///
+    /// let mut config = Config::default().unwrap();
+    /// assert!(config.add_language(String::from("en")).is_ok());
+    ///
+    /// let mut texts = Languages::new(&config);
+    ///
+    /// let result = texts.insert_text_to_language(
+    ///     "en",
+    ///     "pages.home.title",
+    ///     Value::String(String::from("Home page")),
+    /// );
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn insert_text_to_language(
+        &mut self,
+        lang: &str,
+        path: &str,
+        value: Value,
+    ) -> anyhow::Result<Option<Value>> {
+        self.get_language_mut(lang)?.insert_text(path, value)
+    }
+
+    /// Update an existing text value at a path of a specific language.
+    ///
+    /// # Example
+    /// ```rust, ignore
+    /// use languages_rs::{Config, Languages, Value};
+    ///
+    /// let mut config = Config::default().unwrap();
+    /// assert!(config.add_language(String::from("en")).is_ok());
+    ///
+    /// let mut texts = Languages::new(&config);
+    ///
+    /// let result = texts.update_text_from_language("en", "message", Value::String(String::from("Hello")));
+    /// assert!(result.is_ok());
+    /// assert_eq!(result.unwrap(), Value::String(String::from("Hi")));
+    /// ```
+    pub fn update_text_from_language(
+        &mut self,
+        lang: &str,
+        path: &str,
+        value: Value,
+    ) -> anyhow::Result<Value> {
+        self.get_language_mut(lang)?.update_text(path, value)
+    }
+
+    /// Remove the text value at a path of a specific language.
+    ///
+    /// # Example
+    /// ```rust, ignore
+    /// use languages_rs::{Config, Languages, Value};
+    ///
+    /// let mut config = Config::default().unwrap();
+    /// assert!(config.add_language(String::from("en")).is_ok());
+    ///
+    /// let mut texts = Languages::new(&config);
+    ///
+    /// let result = texts.remove_text_from_language("en", "message");
+    /// assert!(result.is_ok());
+    /// assert_eq!(result.unwrap(), Value::String(String::from("Hi")));
+    /// ```
+    pub fn remove_text_from_language(&mut self, lang: &str, path: &str) -> anyhow::Result<Value> {
+        self.get_language_mut(lang)?.remove_text(path)
+    }
+
+    /// Move the text value of a path to a new path in a specific language.
+    ///
+    /// # Example
+    /// ```rust, ignore
+    /// use languages_rs::{Config, Languages};
+    ///
+    /// let mut config = Config::default().unwrap();
+    /// assert!(config.add_language(String::from("en")).is_ok());
+    ///
+    /// let mut texts = Languages::new(&config);
+    /// assert!(texts.rename_text_from_language("en", "message", "messages.first").is_ok());
+    /// ```
+    pub fn rename_text_from_language(
+        &mut self,
+        lang: &str,
+        from: &str,
+        to: &str,
+    ) -> anyhow::Result<()> {
+        self.get_language_mut(lang)?.rename_text(from, to)
+    }
+
+    /// Get the languages with changes that are not saved yet.
+    ///
+    /// # Example
+    /// ```rust, ignore
+    /// use languages_rs::{Config, Languages, Value};
+    ///
+    /// let mut config = Config::default().unwrap();
+    /// assert!(config.add_language(String::from("en")).is_ok());
+    ///
+    /// let mut texts = Languages::new(&config);
+    /// assert!(texts.get_dirty_languages().is_empty());
+    ///
+    /// let result = texts.insert_text_to_language("en", "message", Value::String(String::from("Hi")));
+    /// assert!(result.is_ok());
+    /// assert_eq!(texts.get_dirty_languages(), vec![String::from("en")]);
+    ///
+    /// assert!(texts.save_language("en").is_ok());
+    /// assert!(texts.get_dirty_languages().is_empty());
+    /// ```
+    pub fn get_dirty_languages(&self) -> Vec<String> {
+        self.langs
+            .iter()
+            .filter(|lang_texts| lang_texts.is_dirty())
+            .map(|lang_texts| lang_texts.get_language())
+            .collect()
+    }
+
+    /// Compare the texts of every language of the configuration with a reference language,
+    /// loading them first if needed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::{Config, Languages};
+    ///
+    /// #[cfg(feature = "with-json")]
+    /// fn main() {
+    ///     let config = Config::new("examples/texts/json", vec!["en", "es"]).unwrap();
+    ///     let mut texts = Languages::new(&config);
+    ///
+    ///     let report = texts.check_consistency("en").unwrap();
+    ///     assert!(report.is_consistent());
+    /// }
+    ///
+    /// #[cfg(not(feature = "with-json"))]
+    /// fn main() {}
+    /// ```
+    pub fn check_consistency(&mut self, reference: &str) -> anyhow::Result<ConsistencyReport> {
+        let reference = self.try_get_language(reference)?;
+
+        let mut languages = Vec::new();
+        for lang in self.config.get_languages().iter() {
+            languages.push(self.try_get_language(lang)?);
+        }
+
+        Ok(ConsistencyReport::compare(&reference, &languages))
+    }
+
+    /// Read the texts of several languages, in parallel with the `with-rayon` feature, and add
+    /// them to the cache. Returns every language that failed with its error.
+    pub(crate) fn load_languages(&mut self, langs: &[String]) -> Vec<(String, anyhow::Error)> {
+        let langs: Vec<&String> = langs
+            .iter()
+            .filter(|lang| match self.get_cached_index(lang) {
+                Some(index) => self.langs[index].is_partial(),
+                None => true,
+            })
+            .collect();
+
+        #[cfg(feature = "with-rayon")]
+        let results: Vec<(&String, anyhow::Result<LanguageTexts>)> = {
+            use rayon::prelude::*;
+
+            let languages: &Self = self;
+            langs
+                .into_par_iter()
+                .map(|lang| (lang, languages.read_language(lang)))
+                .collect()
+        };
+
+        #[cfg(not(feature = "with-rayon"))]
+        let results: Vec<(&String, anyhow::Result<LanguageTexts>)> = langs
+            .into_iter()
+            .map(|lang| (lang, self.read_language(lang)))
+            .collect();
+
+        let mut errors: Vec<(String, anyhow::Error)> = Vec::new();
+        for (lang, result) in results {
+            match result {
+                Ok(lang_texts) => {
+                    self.cache_language(lang_texts);
+                }
+                Err(e) => errors.push((lang.clone(), e)),
+            }
+        }
+
+        errors
+    }
+
+    /// Read and merge the texts of a language from every source, without using the cache.
+    fn read_language(&self, lang: &str) -> anyhow::Result<LanguageTexts> {
+        self.check_language(lang)?;
+
+        // Generate the language texts object for the texts of every source.
+        let formats = self.config.get_formats();
+        let (texts, text_sources) =
+            match self.read_sources(|source| source.read_language(lang, &formats, &self.files))? {
+                Some(result) => result,
+                None => {
+                    return Err(anyhow::Error::msg(format!(
+                        "Cannot find the texts of the `{}` lang in {}.",
+                        lang,
+                        self.get_sources_names()
+                    )))
+                }
+            };
+
+        let mut lang_texts = LanguageTexts::new(String::from(lang), texts)?;
+        lang_texts.set_text_sources(text_sources);
+
+        Ok(lang_texts)
+    }
+
+    /// Add the texts of a language to the cache, keeping the namespaces that are already in it.
+    fn cache_language(&mut self, mut lang_texts: LanguageTexts) -> LanguageTexts {
+        if let Some(index) = self.get_cached_index(&lang_texts.get_language()) {
+            lang_texts.insert_namespaces_from(self.langs.remove(index));
+        }
+
+        self.langs.push(lang_texts.clone());
+        lang_texts
+    }
+
+    /// Get the texts of a namespace of a language, loading and caching only its file if the
+    /// language is not loaded yet, or `None` if the language does not have the namespace.
+    fn load_namespace(&mut self, lang: &str, namespace: &str) -> anyhow::Result<Option<Value>> {
+        self.check_language(lang)?;
+
+        let cached = self.get_cached_index(lang);
+        if let Some(index) = cached {
+            if let Some(value) = self.langs[index].get_namespace(namespace) {
+                return Ok(Some(value));
+            } else if !self.langs[index].is_partial() {
+                return Ok(None);
+            }
+        }
+
+        let formats = self.config.get_formats();
+        let result = self.read_sources(|source| {
+            Ok(source
+                .read_namespace(lang, namespace, &formats, &self.files)?
+                .map(|value| {
+                    let mut data = HashMap::new();
+                    data.insert(String::from(namespace), value);
+
+                    Value::Object(data)
+                }))
+        })?;
+
+        let (value, text_sources) = match result {
+            Some((Value::Object(mut data), text_sources)) => {
+                (data.remove(namespace).unwrap(), text_sources)
+            }
+            _ => return Ok(None),
+        };
+
+        let index = match cached {
+            Some(index) => index,
+            None => {
+                self.langs
+                    .push(LanguageTexts::new_partial(String::from(lang)));
+                self.langs.len() - 1
+            }
+        };
+
+        self.langs[index].insert_namespace(String::from(namespace), value.clone(), text_sources);
+        Ok(Some(value))
+    }
+
+    /// Get a text of a specific language without using the fallback languages.
+    fn try_get_own_text(&mut self, lang: &str, text: &str) -> anyhow::Result<Option<Value>> {
+        // Load only the namespace of the text when it uses the `namespace:key` syntax.
+        if let Some((namespace, _)) = text.split_once(':') {
+            let is_namespaced = self
+                .config
+                .get_sources()
+                .iter()
+                .any(|source| source.is_namespaced(lang, &self.files));
+
+            // A missing namespace may be a key with `:`, so it uses the whole language.
+            if is_namespaced && self.load_namespace(lang, namespace)?.is_some() {
+                let index = self.get_cached_index(lang).unwrap();
+                return Ok(self.langs[index].try_get_text(text));
+            }
+        }
+
         Ok(self.try_get_language(lang)?.try_get_text(text))
     }
+
+    /// Get the default language of the configuration.
+    fn get_default_language(&self) -> anyhow::Result<String> {
+        match self.config.get_default_language() {
+            Some(lang) => Ok(lang),
+            None => Err(anyhow::Error::msg(
+                "The configuration does not have a default language.",
+            )),
+        }
+    }
+
+    /// Check if the configuration has the language.
+    fn check_language(&self, lang: &str) -> anyhow::Result<()> {
+        if !self.config.get_languages().contains(&String::from(lang)) {
+            return Err(anyhow::Error::msg(format!(
+                "Cannot find the `{}` lang.",
+                lang
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Get the position of a language in the cache.
+    fn get_cached_index(&self, lang: &str) -> Option<usize> {
+        self.langs
+            .iter()
+            .position(|lang_texts| lang_texts.get_language() == *lang)
+    }
+
+    /// Read and merge the texts of every source of the configuration, where the texts of the later
+    /// sources override the texts of the earlier ones. Returns the merged texts and the source
+    /// of every text, or `None` if no source has the texts.
+    fn read_sources<F>(&self, read: F) -> anyhow::Result<Option<(Value, HashMap<String, Source>)>>
+    where
+        F: Fn(&Source) -> anyhow::Result<Option<Value>>,
+    {
+        let mut result: Option<(Value, HashMap<String, Source>)> = None;
+        for source in self.config.get_sources() {
+            if let Some(value) = read(&source)? {
+                if !value.is_object() {
+                    return Err(anyhow::Error::msg(format!("`{}` is not an object.", value)));
+                }
+
+                let (texts, text_sources) =
+                    result.get_or_insert_with(|| (Value::Object(HashMap::new()), HashMap::new()));
+                for path in texts.merge(value) {
+                    text_sources.insert(path, source.clone());
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Get the names of the sources of the configuration for the errors.
+    fn get_sources_names(&self) -> String {
+        self.config
+            .get_sources()
+            .iter()
+            .map(|source| source.to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    /// Get the cached texts of a language to change them, loading them first if needed.
+    fn get_language_mut(&mut self, lang: &str) -> anyhow::Result<&mut LanguageTexts> {
+        self.try_get_language(lang)?;
+
+        match self
+            .langs
+            .iter_mut()
+            .find(|lang_texts| lang_texts.get_language() == *lang)
+        {
+            Some(lang_texts) => Ok(lang_texts),
+            None => Err(anyhow::Error::msg(format!(
+                "Cannot find the `{}` lang.",
+                lang
+            ))),
+        }
+    }
 }
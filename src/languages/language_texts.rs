@@ -1,9 +1,14 @@
-use crate::Value;
+use std::collections::{HashMap, HashSet};
+
+use crate::{value::PathSegment, Source, Value};
 
 #[derive(Clone)]
 pub struct LanguageTexts {
     language: String,
     texts: Value,
+    sources: HashMap<String, Source>,
+    dirty: bool,
+    partial: bool,
 }
 
 impl LanguageTexts {
@@ -27,7 +32,24 @@ impl LanguageTexts {
             return Err(anyhow::Error::msg(format!("`{}` is not an object.", texts)));
         }
 
-        Ok(Self { language, texts })
+        Ok(Self {
+            language,
+            texts,
+            sources: HashMap::new(),
+            dirty: false,
+            partial: false,
+        })
+    }
+
+    /// Create the texts of a language split in namespaces that are not loaded yet.
+    pub(crate) fn new_partial(language: String) -> Self {
+        Self {
+            language,
+            texts: Value::Object(HashMap::new()),
+            sources: HashMap::new(),
+            dirty: false,
+            partial: true,
+        }
     }
 
     /// Get the language of the texts.
@@ -46,6 +68,25 @@ impl LanguageTexts {
         self.language.clone()
     }
 
+    /// Get all the texts of the language.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::{LanguageTexts, Value};
+    ///
+    /// let mut data: HashMap<String, Value> = HashMap::new();
+    /// data.insert(String::from("message"), Value::String(String::from("Hi")));
+    ///
+    /// let texts = LanguageTexts::new(String::from("en"), Value::Object(data.clone()));
+    /// assert!(texts.is_ok());
+    /// assert_eq!(texts.unwrap().get_texts(), Value::Object(data));
+    /// ```
+    pub fn get_texts(&self) -> Value {
+        self.texts.clone()
+    }
+
     /// Get a text value of a language.
     ///
     /// # Example
@@ -65,13 +106,425 @@ impl LanguageTexts {
     /// assert_eq!(texts.try_get_text("message"), Some(Value::String(String::from("Hi"))));
     /// assert_eq!(texts.try_get_text("message2"), Some(Value::String(String::from("Hi 2"))));
     /// ```
+    ///
+    /// # Example with namespaces
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::{LanguageTexts, Value};
+    ///
+    /// let mut checkout: HashMap<String, Value> = HashMap::new();
+    /// checkout.insert(String::from("title"), Value::String(String::from("Checkout")));
+    ///
+    /// let mut data: HashMap<String, Value> = HashMap::new();
+    /// data.insert(String::from("checkout"), Value::Object(checkout));
+    ///
+    /// let texts = LanguageTexts::new(String::from("en"), Value::Object(data)).unwrap();
+    /// assert_eq!(
+    ///     texts.try_get_text("checkout:title"),
+    ///     Some(Value::String(String::from("Checkout"))),
+    /// );
+    /// ```
     pub fn try_get_text(&self, text: &str) -> Option<Value> {
-        if self.texts.is_object() {
-            if let Some(value) = self.texts.get_object().unwrap().get(&String::from(text)) {
+        if let Value::Object(data) = &self.texts {
+            if let Some(value) = data.get(text) {
                 return Some(value.clone());
             }
+
+            // Use the `namespace:key` syntax if the text is not a key.
+            if let Some((namespace, key)) = text.split_once(':') {
+                if let Some(Value::Object(namespace)) = data.get(namespace) {
+                    return namespace.get(key).cloned();
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Get a nested text value of a language using a path like `pages.home.title` or
+    /// `messages[0]`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::{LanguageTexts, Value};
+    ///
+    /// let mut home: HashMap<String, Value> = HashMap::new();
+    /// home.insert(String::from("title"), Value::String(String::from("Home page")));
+    ///
+    /// let mut data: HashMap<String, Value> = HashMap::new();
+    /// data.insert(String::from("home"), Value::Object(home));
+    /// data.insert(
+    ///     String::from("messages"),
+    ///     Value::Array(vec![Value::String(String::from("Message 1"))]),
+    /// );
+    ///
+    /// let texts = LanguageTexts::new(String::from("en"), Value::Object(data)).unwrap();
+    /// assert_eq!(
+    ///     texts.try_get_path("home.title"),
+    ///     Some(Value::String(String::from("Home page"))),
+    /// );
+    /// assert_eq!(
+    ///     texts.try_get_path("messages[0]"),
+    ///     Some(Value::String(String::from("Message 1"))),
+    /// );
+    /// assert_eq!(texts.try_get_path("home.description"), None);
+    /// ```
+    pub fn try_get_path(&self, path: &str) -> Option<Value> {
+        self.texts
+            .get_path(&PathSegment::parse(path).ok()?)
+            .cloned()
+    }
+
+    /// Insert a text value at a path, creating the missing objects and arrays on the way.
+    /// Returns the replaced value if the path already had one.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::{LanguageTexts, Value};
+    ///
+    /// let mut texts = LanguageTexts::new(String::from("en"), Value::Object(HashMap::new())).unwrap();
+    /// assert!(!texts.is_dirty());
+    ///
+    /// let result = texts.insert_text("pages.home.title", Value::String(String::from("Home")));
+    /// assert!(result.is_ok());
+    /// assert_eq!(result.unwrap(), None);
+    /// assert!(texts.is_dirty());
+    ///
+    /// assert_eq!(
+    ///     texts.try_get_path("pages.home.title"),
+    ///     Some(Value::String(String::from("Home"))),
+    /// );
+    ///
+    /// // An invalid path does not leave the missing objects and arrays behind.
+    /// assert!(texts.insert_text("pages.list[5]", Value::String(String::from("Item"))).is_err());
+    /// assert_eq!(texts.try_get_path("pages.list"), None);
+    /// ```
+    pub fn insert_text(&mut self, path: &str, value: Value) -> anyhow::Result<Option<Value>> {
+        let old_value = self.texts.insert_path(&PathSegment::parse(path)?, value)?;
+        self.forget_text_sources(path);
+        self.dirty = true;
+
+        Ok(old_value)
+    }
+
+    /// Update an existing text value at a path and return the previous value.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::{LanguageTexts, Value};
+    ///
+    /// let mut data: HashMap<String, Value> = HashMap::new();
+    /// data.insert(String::from("message"), Value::String(String::from("Hi")));
+    ///
+    /// let mut texts = LanguageTexts::new(String::from("en"), Value::Object(data)).unwrap();
+    ///
+    /// let result = texts.update_text("message", Value::String(String::from("Hello")));
+    /// assert!(result.is_ok());
+    /// assert_eq!(result.unwrap(), Value::String(String::from("Hi")));
+    ///
+    /// assert!(texts.update_text("other", Value::String(String::from("Hi"))).is_err());
+    /// ```
+    pub fn update_text(&mut self, path: &str, value: Value) -> anyhow::Result<Value> {
+        let segments = PathSegment::parse(path)?;
+        if self.texts.get_path(&segments).is_none() {
+            return Err(anyhow::Error::msg(format!(
+                "Cannot find the `{}` text.",
+                path
+            )));
+        }
+
+        let old_value = self.texts.insert_path(&segments, value)?.unwrap();
+        self.forget_text_sources(path);
+        self.dirty = true;
+
+        Ok(old_value)
+    }
+
+    /// Remove the text value at a path and return it.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::{LanguageTexts, Value};
+    ///
+    /// let mut data: HashMap<String, Value> = HashMap::new();
+    /// data.insert(String::from("message"), Value::String(String::from("Hi")));
+    ///
+    /// let mut texts = LanguageTexts::new(String::from("en"), Value::Object(data)).unwrap();
+    ///
+    /// let result = texts.remove_text("message");
+    /// assert!(result.is_ok());
+    /// assert_eq!(result.unwrap(), Value::String(String::from("Hi")));
+    /// assert_eq!(texts.try_get_text("message"), None);
+    ///
+    /// assert!(texts.remove_text("message").is_err());
+    /// ```
+    pub fn remove_text(&mut self, path: &str) -> anyhow::Result<Value> {
+        match self.texts.remove_path(&PathSegment::parse(path)?) {
+            Some(value) => {
+                self.forget_text_sources(path);
+                self.dirty = true;
+                Ok(value)
+            }
+            None => Err(anyhow::Error::msg(format!(
+                "Cannot find the `{}` text.",
+                path
+            ))),
+        }
+    }
+
+    /// Move the text value of a path to a new path that does not exist yet.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::{LanguageTexts, Value};
+    ///
+    /// let mut data: HashMap<String, Value> = HashMap::new();
+    /// data.insert(String::from("title"), Value::String(String::from("Home page")));
+    ///
+    /// let mut texts = LanguageTexts::new(String::from("en"), Value::Object(data)).unwrap();
+    ///
+    /// assert!(texts.rename_text("title", "home.title").is_ok());
+    /// assert_eq!(texts.try_get_text("title"), None);
+    /// assert_eq!(
+    ///     texts.try_get_path("home.title"),
+    ///     Some(Value::String(String::from("Home page"))),
+    /// );
+    /// ```
+    pub fn rename_text(&mut self, from: &str, to: &str) -> anyhow::Result<()> {
+        let from_segments = PathSegment::parse(from)?;
+        let to_segments = PathSegment::parse(to)?;
+
+        if self.texts.get_path(&to_segments).is_some() {
+            return Err(anyhow::Error::msg(format!(
+                "The `{}` text already exists.",
+                to
+            )));
+        } else if to_segments.starts_with(&from_segments) {
+            return Err(anyhow::Error::msg(format!(
+                "Cannot move the `{}` text inside itself.",
+                from
+            )));
+        }
+
+        let value = match self.texts.get_path(&from_segments) {
+            Some(value) => value.clone(),
+            None => {
+                return Err(anyhow::Error::msg(format!(
+                    "Cannot find the `{}` text.",
+                    from
+                )))
+            }
+        };
+
+        // Insert first so an invalid destination leaves the texts untouched.
+        let mut texts = self.texts.clone();
+        texts.insert_path(&to_segments, value)?;
+        texts.remove_path(&from_segments);
+
+        self.texts = texts;
+        self.forget_text_sources(from);
+        self.forget_text_sources(to);
+        self.dirty = true;
+
+        Ok(())
+    }
+
+    /// Get the source a text value was loaded from when the language is merged from several
+    /// sources. The texts changed in memory do not have a source.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::{Config, Languages, Source, Value};
+    ///
+    /// #[cfg(feature = "with-json")]
+    /// fn main() {
+    ///     let directory = std::env::temp_dir().join("languages-rs-get-text-source");
+    ///     std::fs::create_dir_all(directory.join("languages")).unwrap();
+    ///     std::fs::create_dir_all(directory.join("overrides")).unwrap();
+    ///     std::fs::write(
+    ///         directory.join("languages/en.json"),
+    ///         r#"{ "pages": { "home": { "title": "Home", "subtitle": "Welcome" } } }"#,
+    ///     )
+    ///     .unwrap();
+    ///     std::fs::write(
+    ///         directory.join("overrides/en.json"),
+    ///         r#"{ "pages": { "home": { "title": "My home" } } }"#,
+    ///     )
+    ///     .unwrap();
+    ///
+    ///     let mut config = Config::new(directory.join("languages"), vec!["en"]).unwrap();
+    ///     assert!(config.add_directory(directory.join("overrides")).is_ok());
+    ///
+    ///     let mut texts = Languages::new(&config);
+    ///     let texts_en = texts.try_get_language("en").unwrap();
+    ///
+    ///     // The overrides win key by key and the other keys of the base directory are kept.
+    ///     assert_eq!(
+    ///         texts_en.try_get_path("pages.home.title"),
+    ///         Some(Value::String(String::from("My home")))
+    ///     );
+    ///     assert_eq!(
+    ///         texts_en.try_get_path("pages.home.subtitle"),
+    ///         Some(Value::String(String::from("Welcome")))
+    ///     );
+    ///
+    ///     assert_eq!(
+    ///         texts_en.get_text_source("pages.home.title"),
+    ///         Some(Source::Directory(directory.join("overrides")))
+    ///     );
+    ///     assert_eq!(
+    ///         texts_en.get_text_source("pages.home.subtitle"),
+    ///         Some(Source::Directory(directory.join("languages")))
+    ///     );
+    /// }
+    ///
+    /// #[cfg(not(feature = "with-json"))]
+    /// fn main() {}
+    /// ```
+    pub fn get_text_source(&self, path: &str) -> Option<Source> {
+        let mut segments = PathSegment::parse(path).ok()?;
+        while !segments.is_empty() {
+            if let Some(source) = self.sources.get(&PathSegment::join(&segments)) {
+                return Some(source.clone());
+            }
+
+            segments.pop();
         }
 
         None
     }
+
+    /// Check if the texts were changed since they were loaded or saved.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::{LanguageTexts, Value};
+    ///
+    /// let mut texts = LanguageTexts::new(String::from("en"), Value::Object(HashMap::new())).unwrap();
+    /// assert!(!texts.is_dirty());
+    ///
+    /// assert!(texts.insert_text("message", Value::String(String::from("Hi"))).is_ok());
+    /// assert!(texts.is_dirty());
+    ///
+    /// texts.set_dirty(false);
+    /// assert!(!texts.is_dirty());
+    /// ```
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Change the dirty flag of the texts.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::{LanguageTexts, Value};
+    ///
+    /// let mut texts = LanguageTexts::new(String::from("en"), Value::Object(HashMap::new())).unwrap();
+    ///
+    /// texts.set_dirty(true);
+    /// assert!(texts.is_dirty());
+    /// ```
+    pub fn set_dirty(&mut self, dirty: bool) {
+        self.dirty = dirty;
+    }
+
+    /// Check if only some namespaces of the language are loaded.
+    pub(crate) fn is_partial(&self) -> bool {
+        self.partial
+    }
+
+    /// Get the texts of a loaded namespace.
+    pub(crate) fn get_namespace(&self, namespace: &str) -> Option<Value> {
+        match &self.texts {
+            Value::Object(data) => data.get(namespace).cloned(),
+            _ => None,
+        }
+    }
+
+    /// Add the texts of a namespace loaded from its sources.
+    pub(crate) fn insert_namespace(
+        &mut self,
+        namespace: String,
+        value: Value,
+        sources: HashMap<String, Source>,
+    ) {
+        if let Value::Object(data) = &mut self.texts {
+            self.sources
+                .retain(|path, _| !PathSegment::is_related(path, &namespace));
+            self.sources.extend(sources);
+
+            data.insert(namespace, value);
+        }
+    }
+
+    /// Replace the namespaces of these texts with the namespaces loaded in partial texts.
+    pub(crate) fn insert_namespaces_from(&mut self, partial: LanguageTexts) {
+        if let Value::Object(data) = partial.texts {
+            for (namespace, value) in data {
+                let sources = partial
+                    .sources
+                    .iter()
+                    .filter(|(path, _)| PathSegment::is_related(path, &namespace))
+                    .map(|(path, source)| (path.clone(), source.clone()))
+                    .collect();
+
+                self.insert_namespace(namespace, value, sources);
+            }
+        }
+
+        self.dirty = self.dirty || partial.dirty;
+    }
+
+    /// Change the sources of the texts, keeping only the paths of the current texts.
+    pub(crate) fn set_text_sources(&mut self, mut sources: HashMap<String, Source>) {
+        let paths: HashSet<String> = self.texts.get_text_paths().into_iter().collect();
+        sources.retain(|path, _| paths.contains(path));
+
+        self.sources = sources;
+    }
+
+    /// Get the texts to write to a source, where the texts loaded from other sources are replaced
+    /// with the original texts of the source or removed if the source does not have them. The
+    /// texts changed in memory are written to the source too.
+    pub(crate) fn get_source_texts(&self, source: &Source, original: Option<&Value>) -> Value {
+        let mut texts = self.texts.clone();
+        for (path, text_source) in self.sources.iter() {
+            if text_source == source {
+                continue;
+            }
+
+            let segments = match PathSegment::parse(path) {
+                Ok(segments) => segments,
+                Err(_) => continue,
+            };
+
+            texts.remove_path(&segments);
+            if let Some(value) = original.and_then(|original| original.get_path(&segments)) {
+                let _ = texts.insert_path(&segments, value.clone());
+            }
+        }
+
+        texts
+    }
+
+    /// Forget the sources of the texts changed at a path.
+    fn forget_text_sources(&mut self, path: &str) {
+        self.sources
+            .retain(|source_path, _| !PathSegment::is_related(source_path, path));
+    }
 }
@@ -6,6 +6,34 @@
 //! - `JSON` or `TOML` languages files.
 //! - Only can use Objects, Arrays and Strings.
 //! - Customize the languages directory.
+//! - Split the languages texts in namespace files, like `languages/en/checkout.json`.
+//! - Override the languages texts with other directories.
+//! - Discover the languages of the languages directory.
+//! - Write the languages texts back to `JSON` or `TOML` files.
+//! - Check the languages for missing keys, extra keys and other differences with a reference
+//!   language.
+//! - Convert the languages files between `JSON` and `TOML`, keeping the order of the keys.
+//! - Find the keys used in the Rust source code that the languages do not have, and the unused
+//!   keys.
+//! - Create pseudo-locales like `en-XA` with accents, longer texts, brackets and right-to-left
+//!   texts to test the layouts.
+//! - Precompile the languages texts in a binary catalog for a fast startup.
+//! - Read the languages files from `.zip` or `.tar.gz` archives with the `with-zip` and
+//!   `with-tar` features.
+//! - Export the texts to translators as XLIFF 1.2 or 2.0 files and import the translations with
+//!   the `with-xliff` feature.
+//! - Edit the texts in a spreadsheet with a CSV file that has a column per language with the
+//!   `with-csv` feature.
+//! - Export and import the texts as Android `strings.xml` files with the `with-android` feature
+//!   and as iOS `Localizable.strings` and `Localizable.stringsdict` files with the `with-ios`
+//!   feature.
+//! - Read the Flutter `.arb` files and the i18next v4 JSON files with the `with-json` feature.
+//!   They are not formats of the sources yet, so `load` cannot read them: add their texts with
+//!   `Languages::add_language`.
+//! - Load the languages in parallel with the `with-rayon` feature.
+//! - Load the languages from async code with the `with-async` feature.
+//! - Download the languages files from a server with the `with-http` feature.
+//! - Read and write the languages texts in a SQLite database with the `with-sqlite` feature.
 //!
 //! # JSON Language File
 //! ```json
@@ -74,16 +102,53 @@
 //! }
 //! ```
 
+#[cfg(feature = "with-json")]
+mod arb;
+mod catalog;
 mod config;
+mod consistency;
+mod conversion;
+#[cfg(feature = "with-csv")]
+mod csv_table;
+mod format;
 mod languages;
+mod pseudo_locale;
+mod scanner;
+mod source;
 mod value;
+#[cfg(feature = "with-xliff")]
+mod xliff;
 
-pub use config::Config;
+#[cfg(feature = "with-json")]
+pub use arb::{Arb, ArbMessage};
+pub use catalog::Catalog;
+pub use config::{Config, ConfigBuilder, LoadingStrategy};
+pub use consistency::{ConsistencyIssue, ConsistencyReport, IssueKind};
+pub use conversion::{Conversion, LossyValue};
+#[cfg(feature = "with-csv")]
+pub use csv_table::{CsvProblem, CsvProblemKind, CsvTable};
+pub use format::Format;
 pub use languages::{LanguageTexts, Languages};
+pub use pseudo_locale::PseudoLocale;
+pub use scanner::{KeyReport, KeyScanner, KeyUsage};
+#[cfg(feature = "with-http")]
+pub use source::HttpSource;
+pub use source::Source;
+#[cfg(feature = "with-sqlite")]
+pub use source::SqliteSource;
 pub use value::Value;
+#[cfg(feature = "with-xliff")]
+pub use xliff::{Xliff, XliffUnit, XliffVersion};
 
 /// Load the languages of a configuration and return the `Languages` struct.
 ///
+/// If `Config::is_discover_languages` is enabled, the languages of the sources are discovered
+/// first with `Config::discover_languages`. The texts are only read if the loading strategy is
+/// `LoadingStrategy::Eager`, in parallel with the `with-rayon` feature.
+///
+/// The error lists every language that failed and why. Use `load_partial` to continue with the
+/// languages that succeeded.
+///
 /// # Example
 /// ```rust, ignore
 /// use languages_rs::{Config, load};
@@ -96,12 +161,111 @@ pub use value::Value;
 /// // This loads `languages/en.json` to the cache.
 /// let texts = load(config);
 /// ```
+///
+/// # Example with a broken language
+/// ```rust
+/// use languages_rs::{load, load_partial, Config};
+///
+/// #[cfg(feature = "with-json")]
+/// fn main() {
+///     let directory = std::env::temp_dir().join("languages-rs-load-broken");
+///     std::fs::create_dir_all(&directory).unwrap();
+///     std::fs::write(directory.join("en.json"), r#"{ "a": "Hi", "b": ["x"] }"#).unwrap();
+///     std::fs::write(directory.join("es.json"), r#"{ "a": "Hola", "b": ["x", 1] }"#).unwrap();
+///     std::fs::write(directory.join("fr.json"), r#"{ "a": "Salut" }"#).unwrap();
+///
+///     let config = Config::new(&directory, vec!["en", "es", "fr"]).unwrap();
+///
+///     let error = load(config.clone()).err().unwrap().to_string();
+///     assert!(error.starts_with("Cannot load 1 of the languages:"));
+///     assert!(error.contains("- `es`: "));
+///
+///     let (mut texts, errors) = load_partial(config).unwrap();
+///     assert_eq!(errors.len(), 1);
+///     assert_eq!(errors[0].0, "es");
+///     assert!(texts.try_get_language("en").is_ok());
+///     assert!(texts.try_get_language("fr").is_ok());
+/// }
+///
+/// #[cfg(not(feature = "with-json"))]
+/// fn main() {}
+/// ```
 pub fn load(configuration: Config) -> anyhow::Result<Languages> {
-    let mut languages = Languages::new(&configuration);
-
-    for lang in configuration.get_languages().iter() {
-        languages.try_get_language(lang)?;
+    let (languages, errors) = load_partial(configuration)?;
+    if !errors.is_empty() {
+        return Err(anyhow::Error::msg(format!(
+            "Cannot load {} of the languages:\n{}",
+            errors.len(),
+            errors
+                .iter()
+                .map(|(lang, e)| format!("- `{}`: {}", lang, e))
+                .collect::<Vec<String>>()
+                .join("\n")
+        )));
     }
 
     Ok(languages)
 }
+
+/// Load the languages of a configuration like `load`, but continue with the languages that
+/// succeeded. Returns the `Languages` struct and every language that failed with its error.
+///
+/// # Example
+/// ```rust
+/// use languages_rs::{load_partial, Config};
+///
+/// #[cfg(feature = "with-json")]
+/// fn main() {
+///     let mut config = Config::new("examples/texts/json", vec!["en", "es", "fr"]).unwrap();
+///
+///     let (mut texts, errors) = load_partial(config).unwrap();
+///     assert_eq!(errors.len(), 1);
+///     assert_eq!(errors[0].0, "fr");
+///
+///     assert!(texts.try_get_language("en").is_ok());
+/// }
+///
+/// #[cfg(not(feature = "with-json"))]
+/// fn main() {}
+/// ```
+pub fn load_partial(
+    mut configuration: Config,
+) -> anyhow::Result<(Languages, Vec<(String, anyhow::Error)>)> {
+    if configuration.is_discover_languages() {
+        configuration.discover_languages()?;
+    }
+
+    let mut languages = Languages::new(&configuration);
+    if configuration.get_loading_strategy() == LoadingStrategy::Lazy {
+        return Ok((languages, Vec::new()));
+    }
+
+    let errors = languages.load_languages(&configuration.get_languages());
+    Ok((languages, errors))
+}
+
+/// Load the languages of a configuration like `load`, but reading the files in a blocking
+/// thread of Tokio to not stall the async runtime. Requires the `with-async` feature.
+///
+/// # Example
+/// ```rust
+/// use languages_rs::{load_async, Config};
+///
+/// #[cfg(all(feature = "with-async", feature = "with-json"))]
+/// fn main() {
+///     let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+///     runtime.block_on(async {
+///         let config = Config::new("examples/texts/json", vec!["en", "es"]).unwrap();
+///
+///         let texts = load_async(config).await;
+///         assert!(texts.is_ok());
+///     });
+/// }
+///
+/// #[cfg(not(all(feature = "with-async", feature = "with-json")))]
+/// fn main() {}
+/// ```
+#[cfg(feature = "with-async")]
+pub async fn load_async(configuration: Config) -> anyhow::Result<Languages> {
+    tokio::task::spawn_blocking(move || load(configuration)).await?
+}
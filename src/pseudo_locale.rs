@@ -0,0 +1,401 @@
+use std::collections::HashMap;
+
+use crate::{value::placeholders, Value};
+
+/// A pseudo-locale, like `en-XA`, that rewrites the texts of a reference language to find
+/// hard-coded texts and layouts that cannot fit longer texts.
+///
+/// The placeholders like `{name}`, `%s` or the ICU plural arguments are kept as they are, only
+/// the texts around them are changed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PseudoLocale {
+    language: String,
+    reference: String,
+    expansion: usize,
+    accents: bool,
+    brackets: bool,
+    mirror: bool,
+}
+
+impl PseudoLocale {
+    /// Create a new pseudo-locale of a reference language that uses accents, brackets and makes
+    /// the texts 30% longer.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::PseudoLocale;
+    ///
+    /// let pseudo_locale = PseudoLocale::new("en-XA", "en");
+    /// assert_eq!(pseudo_locale.get_language(), "en-XA");
+    /// assert_eq!(pseudo_locale.get_reference_language(), "en");
+    /// assert_eq!(pseudo_locale.get_expansion(), 30);
+    ///
+    /// assert_eq!(pseudo_locale.localize_text("Hello"), "[Ĥéļļö~~]");
+    /// assert_eq!(
+    ///     pseudo_locale.localize_text("Hi {name}, you have {count, plural, one {# message} other {# messages}}"),
+    ///     "[Ĥî {name}, ýöû ĥåṽé {count, plural, one {# ɱéššåĝé} other {# ɱéššåĝéš}}~~~~~~~~~~]"
+    /// );
+    /// ```
+    pub fn new(language: &str, reference: &str) -> Self {
+        Self {
+            language: String::from(language),
+            reference: String::from(reference),
+            expansion: 30,
+            accents: true,
+            brackets: true,
+            mirror: false,
+        }
+    }
+
+    /// Get the name of the pseudo-locale.
+    pub fn get_language(&self) -> String {
+        self.language.clone()
+    }
+
+    /// Get the language that has the original texts.
+    pub fn get_reference_language(&self) -> String {
+        self.reference.clone()
+    }
+
+    /// Get the percentage that the texts are made longer.
+    pub fn get_expansion(&self) -> usize {
+        self.expansion
+    }
+
+    /// Change the percentage that the texts are made longer, adding `~` at the end.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::PseudoLocale;
+    ///
+    /// let mut pseudo_locale = PseudoLocale::new("en-XA", "en");
+    /// pseudo_locale.set_expansion(100);
+    /// assert_eq!(pseudo_locale.localize_text("Save"), "[Šåṽé~~~~]");
+    ///
+    /// pseudo_locale.set_expansion(0);
+    /// assert_eq!(pseudo_locale.localize_text("Save"), "[Šåṽé]");
+    /// ```
+    pub fn set_expansion(&mut self, expansion: usize) {
+        self.expansion = expansion;
+    }
+
+    /// Check if the letters are changed with accented letters.
+    pub fn is_accents(&self) -> bool {
+        self.accents
+    }
+
+    /// Change the letters with accented letters, like `Hello` to `Ĥéļļö`.
+    pub fn set_accents(&mut self, accents: bool) {
+        self.accents = accents;
+    }
+
+    /// Check if the texts are wrapped in brackets.
+    pub fn is_brackets(&self) -> bool {
+        self.brackets
+    }
+
+    /// Wrap the texts in brackets, like `[Ĥéļļö]`, to find the texts that are cut.
+    pub fn set_brackets(&mut self, brackets: bool) {
+        self.brackets = brackets;
+    }
+
+    /// Check if the texts are written from right to left.
+    pub fn is_mirror(&self) -> bool {
+        self.mirror
+    }
+
+    /// Write the texts from right to left with the Unicode right-to-left override character, to
+    /// check the layouts of right-to-left languages.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::PseudoLocale;
+    ///
+    /// let mut pseudo_locale = PseudoLocale::new("ar-XB", "en");
+    /// pseudo_locale.set_accents(false);
+    /// pseudo_locale.set_brackets(false);
+    /// pseudo_locale.set_expansion(0);
+    /// pseudo_locale.set_mirror(true);
+    ///
+    /// assert_eq!(
+    ///     pseudo_locale.localize_text("Hi {name}"),
+    ///     "\u{202e}Hi \u{202c}{name}"
+    /// );
+    /// ```
+    pub fn set_mirror(&mut self, mirror: bool) {
+        self.mirror = mirror;
+    }
+
+    /// Rewrite a text with the options of the pseudo-locale.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::PseudoLocale;
+    ///
+    /// let pseudo_locale = PseudoLocale::new("en-XA", "en");
+    /// assert_eq!(pseudo_locale.localize_text("%s of %d"), "[%s öƒ %d~~]");
+    /// assert_eq!(pseudo_locale.localize_text("Use '{' for {{name}}"), "[Ûšé '{' ƒöŕ {{name}}~~~]");
+    /// ```
+    pub fn localize_text(&self, text: &str) -> String {
+        let mut segments = Vec::new();
+        collect_segments(&text.chars().collect::<Vec<char>>(), &mut segments);
+
+        let length: usize = segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Text(text) => text.chars().count(),
+                Segment::Placeholder(_) => 0,
+            })
+            .sum();
+
+        let padding = (length * self.expansion).div_ceil(100);
+
+        let mut output = String::new();
+        if self.brackets {
+            output.push('[');
+        }
+
+        for segment in segments.iter() {
+            match segment {
+                Segment::Text(text) => {
+                    let text: String = if self.accents {
+                        text.chars().map(get_accented_letter).collect()
+                    } else {
+                        text.clone()
+                    };
+
+                    if self.mirror {
+                        output.push_str(&format!("\u{202e}{}\u{202c}", text));
+                    } else {
+                        output.push_str(&text);
+                    }
+                }
+                Segment::Placeholder(placeholder) => output.push_str(placeholder),
+            }
+        }
+
+        output.push_str(&"~".repeat(padding));
+
+        if self.brackets {
+            output.push(']');
+        }
+
+        output
+    }
+
+    /// Rewrite every string of the texts with the options of the pseudo-locale.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::{PseudoLocale, Value};
+    ///
+    /// let mut data = HashMap::new();
+    /// data.insert(String::from("hi"), Value::String(String::from("Hi {name}")));
+    ///
+    /// let texts = PseudoLocale::new("en-XA", "en").localize(&Value::Object(data));
+    /// assert_eq!(
+    ///     texts.get_object().unwrap().get("hi"),
+    ///     Some(&Value::String(String::from("[Ĥî {name}~]")))
+    /// );
+    /// ```
+    pub fn localize(&self, texts: &Value) -> Value {
+        match texts {
+            Value::String(text) => Value::String(self.localize_text(text)),
+            Value::Array(values) => {
+                Value::Array(values.iter().map(|value| self.localize(value)).collect())
+            }
+            Value::Object(data) => Value::Object(
+                data.iter()
+                    .map(|(key, value)| (key.clone(), self.localize(value)))
+                    .collect::<HashMap<String, Value>>(),
+            ),
+        }
+    }
+}
+
+/// A part of a text, which is a text to rewrite or a placeholder to keep.
+enum Segment {
+    Text(String),
+    Placeholder(String),
+}
+
+fn push_text(segments: &mut Vec<Segment>, c: char) {
+    match segments.last_mut() {
+        Some(Segment::Text(text)) => text.push(c),
+        _ => segments.push(Segment::Text(c.to_string())),
+    }
+}
+
+fn push_placeholder(segments: &mut Vec<Segment>, placeholder: &[char]) {
+    let placeholder: String = placeholder.iter().collect();
+    match segments.last_mut() {
+        Some(Segment::Placeholder(text)) => text.push_str(&placeholder),
+        _ => segments.push(Segment::Placeholder(placeholder)),
+    }
+}
+
+/// Split a text in the texts to rewrite and the placeholders to keep, reading the messages of the
+/// ICU plural and select options as texts.
+fn collect_segments(chars: &[char], segments: &mut Vec<Segment>) {
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            // Keep the ICU quoted texts like `'{'`.
+            '\'' if matches!(chars.get(i + 1), Some('{') | Some('}')) => {
+                let end = chars[i + 1..]
+                    .iter()
+                    .position(|c| *c == '\'')
+                    .map(|end| i + 1 + end)
+                    .unwrap_or(chars.len() - 1);
+
+                push_placeholder(segments, &chars[i..=end]);
+                i = end;
+            }
+            '{' => match placeholders::find_closing_brace(chars, i) {
+                Some(end) => {
+                    collect_argument(&chars[i..=end], segments);
+                    i = end;
+                }
+                None => push_text(segments, '{'),
+            },
+            '%' => {
+                let end = get_printf_end(chars, i);
+                match end {
+                    Some(end) => {
+                        push_placeholder(segments, &chars[i..=end]);
+                        i = end;
+                    }
+                    None => push_text(segments, '%'),
+                }
+            }
+            c => push_text(segments, c),
+        }
+
+        i += 1;
+    }
+}
+
+/// Read a `{...}` group, keeping the arguments like `{name}` and the keywords of the ICU
+/// arguments, and reading their options like `{# items}` as texts.
+fn collect_argument(chars: &[char], segments: &mut Vec<Segment>) {
+    let content = &chars[1..chars.len() - 1];
+    let comma = content.iter().position(|c| *c == ',');
+    let name: String = content[..comma.unwrap_or(content.len())]
+        .iter()
+        .collect::<String>()
+        .trim()
+        .to_string();
+
+    if !placeholders::is_argument_name(&name) {
+        // A nested message like `{{name}}`.
+        push_placeholder(segments, &chars[..1]);
+        collect_segments(content, segments);
+        push_placeholder(segments, &chars[chars.len() - 1..]);
+        return;
+    }
+
+    let comma = match comma {
+        Some(comma) => comma + 1,
+        None => {
+            push_placeholder(segments, chars);
+            return;
+        }
+    };
+
+    push_placeholder(segments, &chars[..comma + 1]);
+
+    let mut i = comma;
+    while i < content.len() {
+        if content[i] == '{' {
+            if let Some(end) = placeholders::find_closing_brace(content, i) {
+                push_placeholder(segments, &content[i..=i]);
+                collect_segments(&content[i + 1..end], segments);
+                push_placeholder(segments, &content[end..=end]);
+                i = end + 1;
+                continue;
+            }
+        }
+
+        push_placeholder(segments, &content[i..=i]);
+        i += 1;
+    }
+
+    push_placeholder(segments, &chars[chars.len() - 1..]);
+}
+
+/// Get the index of the last character of a printf placeholder like `%s`, `%1$d` or `%.2f`.
+fn get_printf_end(chars: &[char], start: usize) -> Option<usize> {
+    let mut i = start + 1;
+    if chars.get(i) == Some(&'%') {
+        return Some(i);
+    }
+
+    while i < chars.len() && (chars[i].is_ascii_digit() || "$.-+#".contains(chars[i])) {
+        i += 1;
+    }
+
+    match chars.get(i) {
+        Some(c) if c.is_ascii_alphabetic() => Some(i),
+        _ => None,
+    }
+}
+
+fn get_accented_letter(c: char) -> char {
+    match c {
+        'a' => 'å',
+        'b' => 'ƀ',
+        'c' => 'ç',
+        'd' => 'ð',
+        'e' => 'é',
+        'f' => 'ƒ',
+        'g' => 'ĝ',
+        'h' => 'ĥ',
+        'i' => 'î',
+        'j' => 'ĵ',
+        'k' => 'ķ',
+        'l' => 'ļ',
+        'm' => 'ɱ',
+        'n' => 'ñ',
+        'o' => 'ö',
+        'p' => 'þ',
+        'q' => 'ǫ',
+        'r' => 'ŕ',
+        's' => 'š',
+        't' => 'ţ',
+        'u' => 'û',
+        'v' => 'ṽ',
+        'w' => 'ŵ',
+        'x' => 'ẋ',
+        'y' => 'ý',
+        'z' => 'ž',
+        'A' => 'Å',
+        'B' => 'Ɓ',
+        'C' => 'Ç',
+        'D' => 'Ð',
+        'E' => 'É',
+        'F' => 'Ƒ',
+        'G' => 'Ĝ',
+        'H' => 'Ĥ',
+        'I' => 'Î',
+        'J' => 'Ĵ',
+        'K' => 'Ķ',
+        'L' => 'Ļ',
+        'M' => 'Ṁ',
+        'N' => 'Ñ',
+        'O' => 'Ö',
+        'P' => 'Þ',
+        'Q' => 'Ǫ',
+        'R' => 'Ŕ',
+        'S' => 'Š',
+        'T' => 'Ţ',
+        'U' => 'Û',
+        'V' => 'Ṽ',
+        'W' => 'Ŵ',
+        'X' => 'Ẋ',
+        'Y' => 'Ý',
+        'Z' => 'Ž',
+        c => c,
+    }
+}
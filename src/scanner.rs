@@ -0,0 +1,659 @@
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{LanguageTexts, Value};
+
+/// A text key used in a Rust source file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyUsage {
+    key: String,
+    file: PathBuf,
+    line: usize,
+}
+
+impl KeyUsage {
+    /// Get the key, like `hello_world` or `checkout:title`.
+    pub fn get_key(&self) -> String {
+        self.key.clone()
+    }
+
+    /// Get the file where the key is used.
+    pub fn get_file(&self) -> PathBuf {
+        self.file.clone()
+    }
+
+    /// Get the line where the key is used, starting at 1.
+    pub fn get_line(&self) -> usize {
+        self.line
+    }
+}
+
+impl fmt::Display for KeyUsage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{} `{}`", self.file.display(), self.line, self.key)
+    }
+}
+
+/// Find the text keys used in Rust source files, reading the string literals passed to
+/// `try_get_text`, `try_get_text_from_language` and the added functions and macros.
+///
+/// The keys that are not string literals, like variables or `format!` calls, cannot be known
+/// and are ignored.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyScanner {
+    functions: Vec<(String, usize)>,
+    macros: Vec<(String, usize)>,
+}
+
+impl KeyScanner {
+    /// Create a new scanner for the `try_get_text` and `try_get_text_from_language` calls.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::KeyScanner;
+    ///
+    /// let scanner = KeyScanner::new();
+    /// let usages = scanner.scan_source(
+    ///     "src/main.rs",
+    ///     r#"
+    ///     let title = texts.try_get_text("home_title")?;
+    ///     let message = texts.try_get_text_from_language("es", "checkout:message")?;
+    ///     "#,
+    /// );
+    ///
+    /// assert_eq!(usages.len(), 2);
+    /// assert_eq!(usages[0].get_key(), "home_title");
+    /// assert_eq!(usages[0].get_line(), 2);
+    /// assert_eq!(usages[1].get_key(), "checkout:message");
+    /// assert_eq!(usages[1].to_string(), "src/main.rs:3 `checkout:message`");
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            functions: vec![
+                (String::from("try_get_text"), 0),
+                (String::from("try_get_text_from_language"), 1),
+            ],
+            macros: Vec::new(),
+        }
+    }
+
+    /// Add a function or method that receives a text key, where `argument` is the index of the
+    /// key in the arguments.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::KeyScanner;
+    ///
+    /// let mut scanner = KeyScanner::new();
+    /// scanner.add_function("translate", 1);
+    ///
+    /// let usages = scanner.scan_source("src/main.rs", r#"translate(&mut texts, "hi");"#);
+    /// assert_eq!(usages.len(), 1);
+    /// assert_eq!(usages[0].get_key(), "hi");
+    /// ```
+    pub fn add_function(&mut self, name: &str, argument: usize) {
+        self.functions.push((String::from(name), argument));
+    }
+
+    /// Add a macro that receives a text key, like `t!("hello_world")`, where `argument` is the
+    /// index of the key in the arguments.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::KeyScanner;
+    ///
+    /// let mut scanner = KeyScanner::new();
+    /// scanner.add_macro("t", 0);
+    ///
+    /// let usages = scanner.scan_source(
+    ///     "src/main.rs",
+    ///     r#"
+    ///     // t!("commented")
+    ///     println!("{}", t!("greeting", name = "Ana"));
+    ///     let text = "t!(\"inside_a_string\")";
+    ///     "#,
+    /// );
+    ///
+    /// assert_eq!(usages.len(), 1);
+    /// assert_eq!(usages[0].get_key(), "greeting");
+    ///
+    /// // A bad `\u` escape is skipped.
+    /// let usages = scanner.scan_source("src/main.rs", r#"t!("bad\u{"); t!("caf\u{e9}");"#);
+    /// assert_eq!(usages.len(), 2);
+    /// assert_eq!(usages[0].get_key(), "bad{");
+    /// assert_eq!(usages[1].get_key(), "café");
+    /// ```
+    pub fn add_macro(&mut self, name: &str, argument: usize) {
+        self.macros.push((String::from(name), argument));
+    }
+
+    /// Get the keys used in the source code of a file.
+    pub fn scan_source<P: AsRef<Path>>(&self, file: P, source: &str) -> Vec<KeyUsage> {
+        let tokens = tokenize(source);
+
+        let mut usages = Vec::new();
+        for (i, token) in tokens.iter().enumerate() {
+            let name = match &token.kind {
+                TokenKind::Ident(name) => name,
+                _ => continue,
+            };
+
+            let (open, argument) = match tokens.get(i + 1).map(|token| &token.kind) {
+                Some(TokenKind::Punct('(')) => match self.get_function_argument(name) {
+                    Some(argument) => (i + 1, argument),
+                    None => continue,
+                },
+                Some(TokenKind::Punct('!')) => match self.get_macro_argument(name) {
+                    Some(argument) => (i + 2, argument),
+                    None => continue,
+                },
+                _ => continue,
+            };
+
+            if let Some(key) = get_string_argument(&tokens, open, argument) {
+                usages.push(KeyUsage {
+                    key,
+                    file: file.as_ref().to_path_buf(),
+                    line: token.line,
+                });
+            }
+        }
+
+        usages
+    }
+
+    /// Get the keys used in the `.rs` files of a directory and its subdirectories, skipping the
+    /// `target` and hidden directories.
+    pub fn scan_directory<P: AsRef<Path>>(&self, directory: P) -> anyhow::Result<Vec<KeyUsage>> {
+        let directory = directory.as_ref();
+        if !directory.is_dir() {
+            return Err(anyhow::Error::msg(format!(
+                "The directory `{}` does not exist.",
+                directory.display()
+            )));
+        }
+
+        let mut files = Vec::new();
+        collect_rust_files(directory, &mut files)?;
+        files.sort();
+
+        let mut usages = Vec::new();
+        for file in files {
+            let source = fs::read_to_string(&file).map_err(|e| {
+                anyhow::Error::msg(format!("Cannot read `{}`: {}", file.display(), e))
+            })?;
+
+            usages.append(&mut self.scan_source(&file, &source));
+        }
+
+        Ok(usages)
+    }
+
+    fn get_function_argument(&self, name: &str) -> Option<usize> {
+        self.functions
+            .iter()
+            .find(|(function, _)| function == name)
+            .map(|(_, argument)| *argument)
+    }
+
+    fn get_macro_argument(&self, name: &str) -> Option<usize> {
+        self.macros
+            .iter()
+            .find(|(r#macro, _)| r#macro == name)
+            .map(|(_, argument)| *argument)
+    }
+}
+
+impl Default for KeyScanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The keys used in the source code that the texts do not have, and the keys of the texts that
+/// the source code does not use.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyReport {
+    missing: Vec<KeyUsage>,
+    unused: Vec<String>,
+}
+
+impl KeyReport {
+    /// Compare the keys used in the source code with the keys of the texts of a language.
+    ///
+    /// The keys of the texts are the keys of the root object, or the `namespace:key` keys of a
+    /// namespace when the source code uses that syntax for it.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::{KeyReport, KeyScanner, LanguageTexts, Value};
+    ///
+    /// let mut checkout = HashMap::new();
+    /// checkout.insert(String::from("title"), Value::String(String::from("Checkout")));
+    /// checkout.insert(String::from("pay"), Value::String(String::from("Pay")));
+    ///
+    /// let mut data = HashMap::new();
+    /// data.insert(String::from("hi"), Value::String(String::from("Hi")));
+    /// data.insert(String::from("bye"), Value::String(String::from("Bye")));
+    /// data.insert(String::from("checkout"), Value::Object(checkout));
+    /// let texts = LanguageTexts::new(String::from("en"), Value::Object(data)).unwrap();
+    ///
+    /// let usages = KeyScanner::new().scan_source(
+    ///     "src/main.rs",
+    ///     r#"
+    ///     texts.try_get_text("hi");
+    ///     texts.try_get_text("welcome");
+    ///     texts.try_get_text("checkout:title");
+    ///     "#,
+    /// );
+    ///
+    /// let report = KeyReport::compare(&usages, &texts);
+    /// assert!(!report.is_clean());
+    /// assert_eq!(report.get_missing_keys()[0].get_key(), "welcome");
+    /// assert_eq!(
+    ///     report.get_unused_keys(),
+    ///     vec![String::from("bye"), String::from("checkout:pay")]
+    /// );
+    /// ```
+    pub fn compare(usages: &[KeyUsage], texts: &LanguageTexts) -> Self {
+        let missing = usages
+            .iter()
+            .filter(|usage| texts.try_get_text(&usage.key).is_none())
+            .cloned()
+            .collect();
+
+        let is_used = |key: &str| usages.iter().any(|usage| usage.key == key);
+
+        let mut unused = Vec::new();
+        if let Value::Object(data) = texts.get_texts() {
+            let mut keys: Vec<&String> = data.keys().collect();
+            keys.sort();
+
+            for key in keys {
+                if is_used(key) {
+                    continue;
+                }
+
+                let prefix = format!("{}:", key);
+                let is_namespace_used = usages.iter().any(|usage| usage.key.starts_with(&prefix));
+
+                match &data[key] {
+                    Value::Object(namespace) if is_namespace_used => {
+                        let mut namespace_keys: Vec<&String> = namespace.keys().collect();
+                        namespace_keys.sort();
+
+                        for namespace_key in namespace_keys {
+                            let full_key = format!("{}{}", prefix, namespace_key);
+                            if !is_used(&full_key) {
+                                unused.push(full_key);
+                            }
+                        }
+                    }
+                    _ if is_namespace_used => {}
+                    _ => unused.push(key.clone()),
+                }
+            }
+        }
+
+        Self { missing, unused }
+    }
+
+    /// Get the keys used in the source code that the texts do not have.
+    pub fn get_missing_keys(&self) -> Vec<KeyUsage> {
+        self.missing.clone()
+    }
+
+    /// Get the keys of the texts that the source code does not use, sorted by name.
+    pub fn get_unused_keys(&self) -> Vec<String> {
+        self.unused.clone()
+    }
+
+    /// Check if the source code and the texts use the same keys.
+    pub fn is_clean(&self) -> bool {
+        self.missing.is_empty() && self.unused.is_empty()
+    }
+}
+
+impl fmt::Display for KeyReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_clean() {
+            return write!(f, "All the keys are used and exist in the texts.");
+        }
+
+        write!(
+            f,
+            "Found {} missing keys and {} unused keys:",
+            self.missing.len(),
+            self.unused.len()
+        )?;
+
+        for usage in self.missing.iter() {
+            write!(f, "\n- missing {}", usage)?;
+        }
+
+        for key in self.unused.iter() {
+            write!(f, "\n- unused `{}`", key)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn collect_rust_files(directory: &Path, files: &mut Vec<PathBuf>) -> anyhow::Result<()> {
+    for entry in fs::read_dir(directory)? {
+        let path = entry?.path();
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        if name.starts_with('.') {
+            continue;
+        }
+
+        if path.is_dir() {
+            if name != "target" {
+                collect_rust_files(&path, files)?;
+            }
+        } else if name.ends_with(".rs") {
+            files.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, PartialEq)]
+enum TokenKind {
+    Ident(String),
+    Str(String),
+    Punct(char),
+    /// Numbers, characters, lifetimes and byte strings.
+    Other,
+}
+
+struct Token {
+    kind: TokenKind,
+    line: usize,
+}
+
+/// Split the source code in the tokens needed to find the calls, skipping the comments.
+fn tokenize(source: &str) -> Vec<Token> {
+    let chars: Vec<char> = source.chars().collect();
+
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let start_line = line;
+
+        if c == '\n' {
+            line += 1;
+            i += 1;
+        } else if c.is_whitespace() {
+            i += 1;
+        } else if c == '/' && chars.get(i + 1) == Some(&'/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '/' && chars.get(i + 1) == Some(&'*') {
+            // The block comments can be nested.
+            let mut depth = 0;
+            while i < chars.len() {
+                if chars[i] == '/' && chars.get(i + 1) == Some(&'*') {
+                    depth += 1;
+                    i += 2;
+                } else if chars[i] == '*' && chars.get(i + 1) == Some(&'/') {
+                    depth -= 1;
+                    i += 2;
+                    if depth == 0 {
+                        break;
+                    }
+                } else {
+                    if chars[i] == '\n' {
+                        line += 1;
+                    }
+
+                    i += 1;
+                }
+            }
+        } else if c == '"' {
+            let (text, end) = read_string(&chars, i + 1, &mut line);
+            tokens.push(Token {
+                kind: TokenKind::Str(text),
+                line: start_line,
+            });
+            i = end;
+        } else if let Some(end) = read_raw_string_start(&chars, i) {
+            let (text, end) = read_raw_string(&chars, end, &mut line);
+            let kind = if c == 'b' {
+                TokenKind::Other
+            } else {
+                TokenKind::Str(text)
+            };
+
+            tokens.push(Token {
+                kind,
+                line: start_line,
+            });
+            i = end;
+        } else if c == 'b' && chars.get(i + 1) == Some(&'"') {
+            let (_, end) = read_string(&chars, i + 2, &mut line);
+            tokens.push(Token {
+                kind: TokenKind::Other,
+                line: start_line,
+            });
+            i = end;
+        } else if c == '\'' {
+            // A character like `'a'` or `'\n'`, or a lifetime like `'a`.
+            i += 1;
+            if chars.get(i) == Some(&'\\') {
+                i += 2;
+                while i < chars.len() && chars[i] != '\'' {
+                    i += 1;
+                }
+
+                i += 1;
+            } else if chars.get(i + 1) == Some(&'\'') {
+                i += 2;
+            }
+
+            tokens.push(Token {
+                kind: TokenKind::Other,
+                line: start_line,
+            });
+        } else if c.is_alphabetic() || c == '_' {
+            // Skip the prefix of the raw identifiers like `r#type`.
+            if c == 'r' && chars.get(i + 1) == Some(&'#') {
+                i += 2;
+            }
+
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+
+            let ident: String = chars[start..i].iter().collect();
+            tokens.push(Token {
+                kind: TokenKind::Ident(ident),
+                line: start_line,
+            });
+        } else if c.is_ascii_digit() {
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+
+            tokens.push(Token {
+                kind: TokenKind::Other,
+                line: start_line,
+            });
+        } else {
+            tokens.push(Token {
+                kind: TokenKind::Punct(c),
+                line: start_line,
+            });
+            i += 1;
+        }
+    }
+
+    tokens
+}
+
+/// Read a `"..."` string from the character after the quote, returning its text and the index
+/// after the closing quote.
+fn read_string(chars: &[char], mut i: usize, line: &mut usize) -> (String, usize) {
+    let mut text = String::new();
+    while i < chars.len() && chars[i] != '"' {
+        let c = chars[i];
+        i += 1;
+
+        if c == '\n' {
+            *line += 1;
+        }
+
+        if c != '\\' {
+            text.push(c);
+            continue;
+        }
+
+        match chars.get(i) {
+            Some('n') => text.push('\n'),
+            Some('r') => text.push('\r'),
+            Some('t') => text.push('\t'),
+            Some('0') => text.push('\0'),
+            Some('u') => {
+                // A bad escape without its `}` before the end of the string is skipped.
+                let end = chars[i..]
+                    .iter()
+                    .position(|c| *c == '}' || *c == '"')
+                    .map(|end| i + end)
+                    .filter(|end| chars[*end] == '}');
+                if let Some(end) = end {
+                    let code: String = chars
+                        .get(i + 1..end)
+                        .unwrap_or_default()
+                        .iter()
+                        .filter(|c| c.is_ascii_hexdigit())
+                        .collect();
+                    if let Some(c) = u32::from_str_radix(&code, 16).ok().and_then(char::from_u32) {
+                        text.push(c);
+                    }
+
+                    i = end;
+                }
+            }
+            Some('\n') => {
+                // A line break after `\` skips the whitespace of the next line.
+                *line += 1;
+                while i + 1 < chars.len() && chars[i + 1].is_whitespace() {
+                    if chars[i + 1] == '\n' {
+                        *line += 1;
+                    }
+
+                    i += 1;
+                }
+            }
+            Some(c) => text.push(*c),
+            None => {}
+        }
+
+        i += 1;
+    }
+
+    (text, i + 1)
+}
+
+/// Get the index after the opening quote of a raw string like `r#"..."#` or `br"..."`.
+fn read_raw_string_start(chars: &[char], i: usize) -> Option<usize> {
+    let mut j = i;
+    if chars.get(j) == Some(&'b') {
+        j += 1;
+    }
+
+    if chars.get(j) != Some(&'r') {
+        return None;
+    }
+
+    j += 1;
+    while chars.get(j) == Some(&'#') {
+        j += 1;
+    }
+
+    if chars.get(j) == Some(&'"') {
+        Some(j + 1)
+    } else {
+        None
+    }
+}
+
+/// Read a raw string from the character after the opening quote, returning its text and the
+/// index after the closing quote and hashes.
+fn read_raw_string(chars: &[char], start: usize, line: &mut usize) -> (String, usize) {
+    let hashes = chars[..start - 1]
+        .iter()
+        .rev()
+        .take_while(|c| **c == '#')
+        .count();
+
+    let mut i = start;
+    while i < chars.len() {
+        if chars[i] == '"'
+            && chars[i + 1..].len() >= hashes
+            && chars[i + 1..i + 1 + hashes].iter().all(|c| *c == '#')
+        {
+            return (chars[start..i].iter().collect(), i + 1 + hashes);
+        }
+
+        if chars[i] == '\n' {
+            *line += 1;
+        }
+
+        i += 1;
+    }
+
+    (chars[start..].iter().collect(), chars.len())
+}
+
+/// Get the string literal of an argument of a call, where `open` is the index of the opening
+/// delimiter, or `None` if the argument is not a string literal.
+fn get_string_argument(tokens: &[Token], open: usize, argument: usize) -> Option<String> {
+    let close = match tokens.get(open)?.kind {
+        TokenKind::Punct('(') => ')',
+        TokenKind::Punct('[') => ']',
+        TokenKind::Punct('{') => '}',
+        _ => return None,
+    };
+
+    let mut depth = 0;
+    let mut index = 0;
+    let mut argument_tokens: Vec<&TokenKind> = Vec::new();
+    for token in tokens[open + 1..].iter() {
+        match token.kind {
+            TokenKind::Punct('(') | TokenKind::Punct('[') | TokenKind::Punct('{') => depth += 1,
+            TokenKind::Punct(c) if depth == 0 && c == close => break,
+            TokenKind::Punct(')') | TokenKind::Punct(']') | TokenKind::Punct('}') => depth -= 1,
+            TokenKind::Punct(',') if depth == 0 => {
+                index += 1;
+                continue;
+            }
+            _ => {}
+        }
+
+        if index == argument {
+            argument_tokens.push(&token.kind);
+        }
+    }
+
+    // Allow references like `&"key"`.
+    match argument_tokens.as_slice() {
+        [TokenKind::Str(text)] | [TokenKind::Punct('&'), TokenKind::Str(text)] => {
+            Some(text.clone())
+        }
+        _ => None,
+    }
+}
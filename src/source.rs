@@ -0,0 +1,463 @@
+#[cfg(any(feature = "with-zip", feature = "with-tar"))]
+mod archive;
+#[cfg(feature = "with-http")]
+mod http;
+
+#[cfg(feature = "with-sqlite")]
+mod sqlite;
+
+#[cfg(feature = "with-http")]
+pub use http::HttpSource;
+#[cfg(feature = "with-sqlite")]
+pub use sqlite::SqliteSource;
+
+use std::{
+    collections::HashMap,
+    fmt,
+    fs::{self, read_to_string, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    process,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex, PoisonError,
+    },
+    time::SystemTime,
+};
+
+#[cfg(any(feature = "with-zip", feature = "with-tar"))]
+use archive::Archive;
+
+use crate::{config::MANIFEST_FILES, value::PathSegment, Catalog, Format, Value};
+
+/// A place where the languages texts are loaded from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Source {
+    /// A directory with a `<lang>.<ext>` file or a `<lang>/` directory of namespace files per
+    /// language.
+    Directory(PathBuf),
+    /// A binary catalog file written with `Catalog::write`.
+    Catalog(PathBuf),
+    /// A `.zip`, `.tar` or `.tar.gz` archive with the same files as a directory. Requires the
+    /// `with-zip` or `with-tar` feature.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::{load, Config, Source};
+    ///
+    /// #[cfg(all(feature = "with-json", feature = "with-tar"))]
+    /// fn main() {
+    ///     let mut config = Config::new("examples/texts/archives", vec!["en", "es"]).unwrap();
+    ///     assert!(config.add_source(Source::Archive("examples/texts/archives/texts.tar.gz".into())).is_ok());
+    ///
+    ///     let mut texts = load(config).unwrap();
+    ///     assert!(texts.try_get_text_from_language("es", "common:greeting").unwrap().is_some());
+    /// }
+    ///
+    /// #[cfg(not(all(feature = "with-json", feature = "with-tar")))]
+    /// fn main() {}
+    /// ```
+    #[cfg(any(feature = "with-zip", feature = "with-tar"))]
+    Archive(PathBuf),
+    /// A server with a `<base_url>/<lang>.json` file per language. Requires the `with-http`
+    /// feature.
+    #[cfg(feature = "with-http")]
+    Http(HttpSource),
+    /// A SQLite database with a table of `(language, key_path, value)` rows. Requires the
+    /// `with-sqlite` feature.
+    #[cfg(feature = "with-sqlite")]
+    Sqlite(SqliteSource),
+}
+
+/// The files opened by the sources, like the catalogs and the archives, kept to read them only
+/// once and read again only when they change.
+#[derive(Clone, Default)]
+pub(crate) struct SourceFiles {
+    catalogs: OpenedFiles<Catalog>,
+    #[cfg(any(feature = "with-zip", feature = "with-tar"))]
+    archives: OpenedFiles<Archive>,
+}
+
+/// The opened files by path, with the modification time and the size they had.
+type OpenedFiles<T> = Arc<Mutex<HashMap<PathBuf, (Option<SystemTime>, u64, Arc<T>)>>>;
+
+impl SourceFiles {
+    fn get_catalog(&self, path: &Path) -> anyhow::Result<Arc<Catalog>> {
+        get_opened_file(&self.catalogs, path, |path| Catalog::read(path))
+    }
+
+    #[cfg(any(feature = "with-zip", feature = "with-tar"))]
+    fn get_archive(&self, path: &Path) -> anyhow::Result<Arc<Archive>> {
+        get_opened_file(&self.archives, path, Archive::open)
+    }
+}
+
+/// Get an opened file, opening it again if it changed since it was opened.
+fn get_opened_file<T, F>(files: &OpenedFiles<T>, path: &Path, open: F) -> anyhow::Result<Arc<T>>
+where
+    F: FnOnce(&Path) -> anyhow::Result<T>,
+{
+    let metadata = match fs::metadata(path) {
+        Ok(metadata) => metadata,
+        // Let the file report why it cannot be opened.
+        Err(_) => return open(path).map(Arc::new),
+    };
+
+    let modified = metadata.modified().ok();
+    let size = metadata.len();
+
+    let mut files = files.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some((file_modified, file_size, file)) = files.get(path) {
+        if *file_modified == modified && *file_size == size {
+            return Ok(file.clone());
+        }
+    }
+
+    let file = Arc::new(open(path)?);
+    files.insert(path.to_path_buf(), (modified, size, file.clone()));
+
+    Ok(file)
+}
+
+impl Source {
+    /// Read all the texts of a language, or `None` if the source does not have the language.
+    pub(crate) fn read_language(
+        &self,
+        lang: &str,
+        formats: &[Format],
+        files: &SourceFiles,
+    ) -> anyhow::Result<Option<Value>> {
+        match self {
+            Self::Directory(directory) => {
+                let language_directory = directory.join(lang);
+                if language_directory.is_dir() {
+                    let mut data = Value::Object(HashMap::new());
+                    for (namespace, path, format) in
+                        get_namespace_files(&language_directory, formats)?
+                    {
+                        data.insert_path(
+                            &[PathSegment::Key(namespace)],
+                            read_file(&path, format)?,
+                        )?;
+                    }
+
+                    return Ok(Some(data));
+                }
+
+                let (path, format) = get_file(directory, lang, formats)?;
+                if !path.exists() {
+                    return Ok(None);
+                }
+
+                Ok(Some(read_file(&path, format)?))
+            }
+            Self::Catalog(path) => files.get_catalog(path)?.get_language(lang),
+            #[cfg(any(feature = "with-zip", feature = "with-tar"))]
+            Self::Archive(path) => files.get_archive(path)?.read_language(lang, formats),
+            #[cfg(feature = "with-http")]
+            Self::Http(source) => source.read_language(lang),
+            #[cfg(feature = "with-sqlite")]
+            Self::Sqlite(source) => source.read_language(lang),
+        }
+    }
+
+    /// Read the texts of a namespace of a language, or `None` if the source does not have it.
+    pub(crate) fn read_namespace(
+        &self,
+        lang: &str,
+        namespace: &str,
+        formats: &[Format],
+        files: &SourceFiles,
+    ) -> anyhow::Result<Option<Value>> {
+        match self {
+            Self::Directory(directory) => {
+                let language_directory = directory.join(lang);
+                if !language_directory.is_dir() {
+                    return Ok(self.read_language(lang, formats, files)?.and_then(|texts| {
+                        texts
+                            .get_path(&[PathSegment::Key(String::from(namespace))])
+                            .cloned()
+                    }));
+                }
+
+                let (path, format) = get_file(&language_directory, namespace, formats)?;
+                if !path.exists() {
+                    return Ok(None);
+                }
+
+                Ok(Some(read_file(&path, format)?))
+            }
+            #[cfg(any(feature = "with-zip", feature = "with-tar"))]
+            Self::Archive(path) => files
+                .get_archive(path)?
+                .read_namespace(lang, namespace, formats),
+            _ => Ok(self.read_language(lang, formats, files)?.and_then(|texts| {
+                texts
+                    .get_path(&[PathSegment::Key(String::from(namespace))])
+                    .cloned()
+            })),
+        }
+    }
+
+    /// Write all the texts of a language, replacing the texts that the source has.
+    pub(crate) fn write_language(
+        &self,
+        lang: &str,
+        texts: &Value,
+        formats: &[Format],
+    ) -> anyhow::Result<()> {
+        match self {
+            Self::Directory(directory) => {
+                // Write every namespace to its own file if the language is a directory.
+                let language_directory = directory.join(lang);
+                if language_directory.is_dir() {
+                    let data = texts.get_object().unwrap_or_default();
+
+                    // Remove the files of the namespaces that the texts do not have anymore, so
+                    // they are not read again.
+                    for (namespace, path, _) in get_namespace_files(&language_directory, formats)? {
+                        if !data.contains_key(&namespace) {
+                            fs::remove_file(&path).map_err(|e| {
+                                anyhow::Error::msg(format!(
+                                    "Cannot remove `{}`: {}",
+                                    path.display(),
+                                    e
+                                ))
+                            })?;
+                        }
+                    }
+
+                    for (namespace, value) in data {
+                        let (path, format) = get_file(&language_directory, &namespace, formats)?;
+                        write_atomic(&path, &format.serialize(&value)?)?;
+                    }
+                } else {
+                    let (path, format) = get_file(directory, lang, formats)?;
+                    write_atomic(&path, &format.serialize(texts)?)?;
+                }
+
+                Ok(())
+            }
+            #[cfg(feature = "with-sqlite")]
+            Self::Sqlite(source) => source.write_language(lang, texts),
+            _ => Err(anyhow::Error::msg(format!(
+                "Cannot write the texts of the `{}` lang to the {}.",
+                lang, self
+            ))),
+        }
+    }
+
+    /// Get the languages of the source, sorted by name.
+    pub(crate) fn discover_languages(
+        &self,
+        formats: &[Format],
+        files: &SourceFiles,
+    ) -> anyhow::Result<Vec<String>> {
+        match self {
+            Self::Directory(directory) => {
+                let mut languages: Vec<String> = Vec::new();
+                for entry in fs::read_dir(directory)? {
+                    let path = entry?.path();
+                    let name = match path.file_name() {
+                        Some(name) => name.to_string_lossy().to_string(),
+                        None => continue,
+                    };
+
+                    if name.starts_with('.') || MANIFEST_FILES.contains(&name.as_str()) {
+                        continue;
+                    }
+
+                    let language = if path.is_dir() {
+                        if get_namespace_files(&path, formats)?.is_empty() {
+                            continue;
+                        }
+
+                        name
+                    } else {
+                        let format = path.extension().and_then(|extension| {
+                            Format::from_extension(&extension.to_string_lossy())
+                        });
+
+                        match (format, path.file_stem()) {
+                            (Some(format), Some(stem)) if formats.contains(&format) => {
+                                stem.to_string_lossy().to_string()
+                            }
+                            _ => continue,
+                        }
+                    };
+
+                    if !languages.contains(&language) {
+                        languages.push(language);
+                    }
+                }
+
+                languages.sort();
+                Ok(languages)
+            }
+            Self::Catalog(path) => {
+                let mut languages = files.get_catalog(path)?.get_languages();
+                languages.sort();
+                Ok(languages)
+            }
+            #[cfg(any(feature = "with-zip", feature = "with-tar"))]
+            Self::Archive(path) => Ok(files.get_archive(path)?.discover_languages(formats)),
+            // The servers cannot list their files.
+            #[cfg(feature = "with-http")]
+            Self::Http(_) => Ok(Vec::new()),
+            #[cfg(feature = "with-sqlite")]
+            Self::Sqlite(source) => source.discover_languages(),
+        }
+    }
+
+    /// Check if the source has the texts of a language split in namespace files.
+    #[cfg_attr(
+        not(any(feature = "with-zip", feature = "with-tar")),
+        allow(unused_variables)
+    )]
+    pub(crate) fn is_namespaced(&self, lang: &str, files: &SourceFiles) -> bool {
+        match self {
+            Self::Directory(directory) => directory.join(lang).is_dir(),
+            #[cfg(any(feature = "with-zip", feature = "with-tar"))]
+            Self::Archive(path) => files
+                .get_archive(path)
+                .map(|archive| archive.is_namespaced(lang))
+                .unwrap_or(false),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Directory(directory) => write!(f, "directory `{}`", directory.display()),
+            Self::Catalog(path) => write!(f, "catalog `{}`", path.display()),
+            #[cfg(any(feature = "with-zip", feature = "with-tar"))]
+            Self::Archive(path) => write!(f, "archive `{}`", path.display()),
+            #[cfg(feature = "with-http")]
+            Self::Http(source) => write!(f, "URL `{}`", source.get_base_url()),
+            #[cfg(feature = "with-sqlite")]
+            Self::Sqlite(source) => write!(
+                f,
+                "table `{}` of `{}`",
+                source.get_table(),
+                source.get_path().display()
+            ),
+        }
+    }
+}
+
+/// Get the file of a name in a directory, using the first format with an existing file or the
+/// first format if the file does not exist yet.
+pub(crate) fn get_file(
+    directory: &Path,
+    name: &str,
+    formats: &[Format],
+) -> anyhow::Result<(PathBuf, Format)> {
+    if formats.is_empty() {
+        return Err(anyhow::Error::msg("You must define the parse feature."));
+    }
+
+    for format in formats.iter() {
+        let path = directory.join(format!("{}.{}", name, format.get_extension()));
+        if path.exists() {
+            return Ok((path, *format));
+        }
+    }
+
+    Ok((
+        directory.join(format!("{}.{}", name, formats[0].get_extension())),
+        formats[0],
+    ))
+}
+
+/// Get the namespace files of a language directory sorted by name.
+pub(crate) fn get_namespace_files(
+    directory: &Path,
+    formats: &[Format],
+) -> anyhow::Result<Vec<(String, PathBuf, Format)>> {
+    let mut names: Vec<String> = Vec::new();
+    for entry in fs::read_dir(directory)? {
+        let path = entry?.path();
+        let format = path
+            .extension()
+            .and_then(|extension| Format::from_extension(&extension.to_string_lossy()));
+
+        if let (Some(format), Some(name)) = (format, path.file_stem()) {
+            let name = name.to_string_lossy().to_string();
+            if path.is_file() && formats.contains(&format) && !names.contains(&name) {
+                names.push(name);
+            }
+        }
+    }
+
+    names.sort();
+
+    let mut files = Vec::new();
+    for name in names {
+        let (path, format) = get_file(directory, &name, formats)?;
+        files.push((name, path, format));
+    }
+
+    Ok(files)
+}
+
+/// Read the texts of a languages file.
+pub(crate) fn read_file(path: &Path, format: Format) -> anyhow::Result<Value> {
+    // Check if the file exists.
+    if !path.exists() {
+        return Err(anyhow::Error::msg(format!(
+            "Cannot find `{}` file.",
+            path.display()
+        )));
+    } else if !path.is_file() {
+        return Err(anyhow::Error::msg(format!(
+            "The path `{}` is not a file.",
+            path.display()
+        )));
+    }
+
+    format
+        .parse(read_to_string(path)?)
+        .map_err(|e| anyhow::Error::msg(format!("Cannot parse `{}` file: {}", path.display(), e)))
+}
+
+/// The number of temporary files created by `write_atomic`.
+static TEMPORARY_FILES: AtomicUsize = AtomicUsize::new(0);
+
+/// Write a file replacing its contents atomically.
+pub(crate) fn write_atomic<C: AsRef<[u8]>>(path: &Path, contents: C) -> anyhow::Result<()> {
+    let file_name = match path.file_name() {
+        Some(file_name) => file_name.to_string_lossy().to_string(),
+        None => {
+            return Err(anyhow::Error::msg(format!(
+                "The path `{}` is not a file.",
+                path.display()
+            )))
+        }
+    };
+
+    // The process ID and a counter keep the temporary files of concurrent saves apart.
+    let temporary_path = path.with_file_name(format!(
+        ".{}.{}.{}.tmp",
+        file_name,
+        process::id(),
+        TEMPORARY_FILES.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    let result = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&temporary_path)
+        .and_then(|mut file| {
+            file.write_all(contents.as_ref())?;
+            file.sync_all()
+        });
+
+    if let Err(e) = result.and_then(|_| fs::rename(&temporary_path, path)) {
+        let _ = fs::remove_file(&temporary_path);
+        return Err(e.into());
+    }
+
+    Ok(())
+}
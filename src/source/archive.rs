@@ -0,0 +1,250 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use crate::{value::PathSegment, Format, Value};
+
+/// The files of a `.zip`, `.tar` or `.tar.gz` archive, read in memory.
+pub(crate) struct Archive {
+    path: PathBuf,
+    entries: Vec<(String, Vec<u8>)>,
+}
+
+impl Archive {
+    /// Read every file of an archive, using its extension to know its type.
+    pub(crate) fn open(path: &Path) -> anyhow::Result<Self> {
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+
+        let file = File::open(path).map_err(|e| {
+            anyhow::Error::msg(format!(
+                "Cannot open the archive `{}`: {}",
+                path.display(),
+                e
+            ))
+        })?;
+
+        let entries = if name.ends_with(".zip") {
+            read_zip(file)?
+        } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            read_tar_gz(file)?
+        } else if name.ends_with(".tar") {
+            read_tar(file)?
+        } else {
+            return Err(anyhow::Error::msg(format!(
+                "The archive `{}` must be a `.zip`, `.tar` or `.tar.gz` file.",
+                path.display()
+            )));
+        };
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            entries: entries
+                .into_iter()
+                .map(|(name, bytes)| (String::from(name.trim_start_matches("./")), bytes))
+                .collect(),
+        })
+    }
+
+    /// Read all the texts of a language from a `<lang>.<ext>` file or the namespace files of a
+    /// `<lang>/` directory, or `None` if the archive does not have the language.
+    pub(crate) fn read_language(
+        &self,
+        lang: &str,
+        formats: &[Format],
+    ) -> anyhow::Result<Option<Value>> {
+        if self.is_namespaced(lang) {
+            let mut data = Value::Object(HashMap::new());
+            for namespace in self.get_namespaces(lang, formats) {
+                if let Some(value) = self.read_file(&format!("{}/{}", lang, namespace), formats)? {
+                    data.insert_path(&[PathSegment::Key(namespace)], value)?;
+                }
+            }
+
+            return Ok(Some(data));
+        }
+
+        self.read_file(lang, formats)
+    }
+
+    /// Read the texts of a namespace of a language, or `None` if the archive does not have it.
+    pub(crate) fn read_namespace(
+        &self,
+        lang: &str,
+        namespace: &str,
+        formats: &[Format],
+    ) -> anyhow::Result<Option<Value>> {
+        if self.is_namespaced(lang) {
+            return self.read_file(&format!("{}/{}", lang, namespace), formats);
+        }
+
+        Ok(self.read_language(lang, formats)?.and_then(|texts| {
+            texts
+                .get_path(&[PathSegment::Key(String::from(namespace))])
+                .cloned()
+        }))
+    }
+
+    /// Get the languages of the archive, sorted by name.
+    pub(crate) fn discover_languages(&self, formats: &[Format]) -> Vec<String> {
+        let mut languages: Vec<String> = Vec::new();
+        for (name, _) in self.entries.iter() {
+            let language = match name.split_once('/') {
+                Some((language, file)) if !file.contains('/') => {
+                    split_extension(file, formats).map(|_| language)
+                }
+                Some(_) => None,
+                None => split_extension(name, formats).map(|(stem, _)| stem),
+            };
+
+            if let Some(language) = language {
+                if !language.starts_with('.') && !languages.iter().any(|e| e == language) {
+                    languages.push(String::from(language));
+                }
+            }
+        }
+
+        languages.sort();
+        languages
+    }
+
+    /// Check if the archive has the texts of a language split in namespace files.
+    pub(crate) fn is_namespaced(&self, lang: &str) -> bool {
+        let prefix = format!("{}/", lang);
+        self.entries
+            .iter()
+            .any(|(name, _)| name.starts_with(&prefix))
+    }
+
+    /// Get the namespaces of a `<lang>/` directory sorted by name.
+    fn get_namespaces(&self, lang: &str, formats: &[Format]) -> Vec<String> {
+        let prefix = format!("{}/", lang);
+
+        let mut namespaces: Vec<String> = Vec::new();
+        for (name, _) in self.entries.iter() {
+            let file = match name.strip_prefix(&prefix) {
+                Some(file) if !file.contains('/') => file,
+                _ => continue,
+            };
+
+            if let Some((namespace, _)) = split_extension(file, formats) {
+                if !namespaces.iter().any(|e| e == namespace) {
+                    namespaces.push(String::from(namespace));
+                }
+            }
+        }
+
+        namespaces.sort();
+        namespaces
+    }
+
+    /// Read the `<name>.<ext>` file of the first format that the archive has.
+    fn read_file(&self, name: &str, formats: &[Format]) -> anyhow::Result<Option<Value>> {
+        for format in formats.iter() {
+            let file = format!("{}.{}", name, format.get_extension());
+            if let Some((_, bytes)) = self.entries.iter().find(|(entry, _)| *entry == file) {
+                let text = String::from_utf8(bytes.clone()).map_err(|_| {
+                    anyhow::Error::msg(format!(
+                        "The file `{}` of the archive `{}` is not UTF-8.",
+                        file,
+                        self.path.display()
+                    ))
+                })?;
+
+                return format.parse(text).map(Some).map_err(|e| {
+                    anyhow::Error::msg(format!(
+                        "Cannot parse the file `{}` of the archive `{}`: {}",
+                        file,
+                        self.path.display(),
+                        e
+                    ))
+                });
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+/// Split the name and the format of a file like `en.json`, if the format is used.
+fn split_extension<'a>(file: &'a str, formats: &[Format]) -> Option<(&'a str, Format)> {
+    let (stem, extension) = file.rsplit_once('.')?;
+    let format = Format::from_extension(extension)?;
+
+    if stem.is_empty() || !formats.contains(&format) {
+        return None;
+    }
+
+    Some((stem, format))
+}
+
+#[cfg(feature = "with-zip")]
+fn read_zip(file: File) -> anyhow::Result<Vec<(String, Vec<u8>)>> {
+    let mut archive = zip::ZipArchive::new(file)?;
+
+    let mut entries = Vec::new();
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i)?;
+        if entry.is_dir() {
+            continue;
+        }
+
+        let mut bytes = Vec::new();
+        entry.read_to_end(&mut bytes)?;
+        entries.push((String::from(entry.name()), bytes));
+    }
+
+    Ok(entries)
+}
+
+#[cfg(not(feature = "with-zip"))]
+fn read_zip(_file: File) -> anyhow::Result<Vec<(String, Vec<u8>)>> {
+    Err(anyhow::Error::msg(
+        "You must enable the `with-zip` feature.",
+    ))
+}
+
+#[cfg(feature = "with-tar")]
+fn read_tar_gz(file: File) -> anyhow::Result<Vec<(String, Vec<u8>)>> {
+    read_tar(flate2::read::GzDecoder::new(file))
+}
+
+#[cfg(not(feature = "with-tar"))]
+fn read_tar_gz(_file: File) -> anyhow::Result<Vec<(String, Vec<u8>)>> {
+    Err(anyhow::Error::msg(
+        "You must enable the `with-tar` feature.",
+    ))
+}
+
+#[cfg(feature = "with-tar")]
+fn read_tar<R: Read>(reader: R) -> anyhow::Result<Vec<(String, Vec<u8>)>> {
+    let mut archive = tar::Archive::new(reader);
+
+    let mut entries = Vec::new();
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+
+        let name = entry.path()?.to_string_lossy().replace('\\', "/");
+
+        let mut bytes = Vec::new();
+        entry.read_to_end(&mut bytes)?;
+        entries.push((name, bytes));
+    }
+
+    Ok(entries)
+}
+
+#[cfg(not(feature = "with-tar"))]
+fn read_tar<R: Read>(_reader: R) -> anyhow::Result<Vec<(String, Vec<u8>)>> {
+    Err(anyhow::Error::msg(
+        "You must enable the `with-tar` feature.",
+    ))
+}
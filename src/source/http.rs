@@ -0,0 +1,253 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::write_atomic;
+use crate::{config::check_directory, Format, Value};
+
+/// A server with a `<base_url>/<lang>.json` file per language.
+///
+/// The files are downloaded every time a language is loaded, using the `ETag` and `Last-Modified`
+/// headers of the cached copy to only download the files that changed. If the server fails, the
+/// cached copy is used, so the application can start offline.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HttpSource {
+    base_url: String,
+    cache_directory: Option<PathBuf>,
+    timeout: Duration,
+}
+
+/// The headers of a cached file to refresh it conditionally.
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct CacheHeaders {
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+impl HttpSource {
+    /// Create a new HTTP source without a cache directory.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::{Config, HttpSource, Source};
+    ///
+    /// let mut config = Config::new("examples/texts/json", vec!["en"]).unwrap();
+    ///
+    /// let source = HttpSource::new("https://cdn.example.com/languages");
+    /// assert_eq!(source.get_base_url(), "https://cdn.example.com/languages");
+    ///
+    /// assert!(config.add_source(Source::Http(source)).is_ok());
+    /// ```
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            base_url: String::from(base_url.trim_end_matches('/')),
+            cache_directory: None,
+            timeout: Duration::from_secs(10),
+        }
+    }
+
+    /// Get the URL of the languages files.
+    pub fn get_base_url(&self) -> String {
+        self.base_url.clone()
+    }
+
+    /// Get the directory where the downloaded files are cached.
+    pub fn get_cache_directory(&self) -> Option<PathBuf> {
+        self.cache_directory.clone()
+    }
+
+    /// Change the directory where the downloaded files are cached, creating it if it does not
+    /// exist.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::HttpSource;
+    ///
+    /// let mut source = HttpSource::new("https://cdn.example.com/languages");
+    /// assert!(source.set_cache_directory("examples/texts/json/en.json").is_err());
+    /// ```
+    pub fn set_cache_directory<P: AsRef<Path>>(&mut self, directory: P) -> anyhow::Result<()> {
+        let directory = directory.as_ref();
+        if !directory.exists() {
+            fs::create_dir_all(directory)?;
+        }
+
+        check_directory(directory)?;
+        self.cache_directory = Some(directory.to_path_buf());
+        Ok(())
+    }
+
+    /// Get the maximum time to download a file.
+    pub fn get_timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Change the maximum time to download a file.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Get the URL of the file of a language.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::HttpSource;
+    ///
+    /// let source = HttpSource::new("https://cdn.example.com/languages/");
+    /// assert_eq!(source.get_url("en"), "https://cdn.example.com/languages/en.json");
+    /// ```
+    pub fn get_url(&self, lang: &str) -> String {
+        format!("{}/{}.json", self.base_url, lang)
+    }
+
+    /// Download the texts of a language, or `None` if the server does not have the language.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::thread;
+    ///
+    /// use languages_rs::{HttpSource, Value};
+    /// use tiny_http::{Header, Response, Server};
+    ///
+    /// let server = Server::http("127.0.0.1:0").unwrap();
+    /// let base_url = format!("http://{}", server.server_addr());
+    ///
+    /// thread::spawn(move || {
+    ///     for request in server.incoming_requests() {
+    ///         let etag = Header::from_bytes("ETag", "\"v1\"").unwrap();
+    ///         let response = match (request.url(), request.headers().iter().any(|h| h.field.equiv("If-None-Match"))) {
+    ///             ("/en.json", false) => Response::from_string("{\"hi\": \"Hi\"}").with_header(etag),
+    ///             ("/en.json", true) => Response::from_string("").with_status_code(304),
+    ///             ("/it.json", false) => Response::from_string("{\"hi\": \"Ciao\"}").with_header(etag),
+    ///             ("/it.json", true) => Response::from_string("<html>Bad gateway</html>"),
+    ///             ("/pt.json", false) => Response::from_string("{\"hi\": \"Oi\"}").with_header(etag),
+    ///             ("/pt.json", true) => Response::from_string("{\"hi\": [1]}"),
+    ///             ("/es.json", _) => Response::from_string("").with_status_code(500),
+    ///             _ => Response::from_string("").with_status_code(404),
+    ///         };
+    ///
+    ///         request.respond(response).unwrap();
+    ///     }
+    /// });
+    ///
+    /// let cache_directory = std::env::temp_dir().join("languages-rs-http-source-example");
+    /// let _ = std::fs::remove_dir_all(&cache_directory);
+    ///
+    /// let mut source = HttpSource::new(&base_url);
+    /// assert!(source.set_cache_directory(&cache_directory).is_ok());
+    ///
+    /// // The first request downloads the file and the second one uses the cached copy.
+    /// let texts = source.read_language("en").unwrap().unwrap();
+    /// assert_eq!(source.read_language("en").unwrap(), Some(texts));
+    ///
+    /// // An invalid response uses the cached copy too.
+    /// let texts = source.read_language("it").unwrap().unwrap();
+    /// assert_eq!(source.read_language("it").unwrap(), Some(texts));
+    ///
+    /// // And the texts that are not language texts, like numbers.
+    /// let texts = source.read_language("pt").unwrap().unwrap();
+    /// assert_eq!(source.read_language("pt").unwrap(), Some(texts));
+    ///
+    /// assert!(source.read_language("es").is_err());
+    /// assert_eq!(source.read_language("fr").unwrap(), None);
+    /// ```
+    pub fn read_language(&self, lang: &str) -> anyhow::Result<Option<Value>> {
+        let cache_file = self
+            .cache_directory
+            .as_ref()
+            .map(|directory| directory.join(format!("{}.json", lang)));
+        let headers_file = self
+            .cache_directory
+            .as_ref()
+            .map(|directory| directory.join(format!(".{}.json.headers", lang)));
+
+        let cached = match &cache_file {
+            Some(cache_file) if cache_file.is_file() => Some(cache_file),
+            _ => None,
+        };
+
+        let mut request = ureq::AgentBuilder::new()
+            .timeout(self.timeout)
+            .build()
+            .get(&self.get_url(lang));
+
+        if let (Some(_), Some(headers_file)) = (cached, &headers_file) {
+            let headers: CacheHeaders = fs::read_to_string(headers_file)
+                .ok()
+                .and_then(|headers| serde_json::from_str(&headers).ok())
+                .unwrap_or_default();
+
+            if let Some(etag) = &headers.etag {
+                request = request.set("If-None-Match", etag);
+            }
+
+            if let Some(last_modified) = &headers.last_modified {
+                request = request.set("If-Modified-Since", last_modified);
+            }
+        }
+
+        let error = match request.call() {
+            Ok(response) if response.status() == 304 => match cached {
+                Some(cache_file) => return Ok(Some(read_cache_file(cache_file)?)),
+                None => anyhow::Error::msg(format!(
+                    "The server returned 304 for `{}` without a cached copy.",
+                    self.get_url(lang)
+                )),
+            },
+            Ok(response) => {
+                let headers = CacheHeaders {
+                    etag: response.header("ETag").map(String::from),
+                    last_modified: response.header("Last-Modified").map(String::from),
+                };
+
+                // A truncated or invalid body is a failure of the server too.
+                let result = response
+                    .into_string()
+                    .map_err(anyhow::Error::from)
+                    .and_then(|text| {
+                        let texts = Format::Json.parse(text.clone())?;
+                        if !texts.is_object() {
+                            return Err(anyhow::Error::msg("The texts are not an object."));
+                        }
+
+                        Ok((text, texts))
+                    });
+
+                match result {
+                    Ok((text, texts)) => {
+                        if let (Some(cache_file), Some(headers_file)) = (&cache_file, &headers_file)
+                        {
+                            write_atomic(cache_file, &text)?;
+                            write_atomic(headers_file, &serde_json::to_string(&headers)?)?;
+                        }
+
+                        return Ok(Some(texts));
+                    }
+                    Err(e) => anyhow::Error::msg(format!(
+                        "Cannot read the texts of `{}`: {}",
+                        self.get_url(lang),
+                        e
+                    )),
+                }
+            }
+            Err(ureq::Error::Status(404, _)) => return Ok(None),
+            Err(e) => {
+                anyhow::Error::msg(format!("Cannot download `{}`: {}", self.get_url(lang), e))
+            }
+        };
+
+        // Use the cached copy when the server fails.
+        match cached {
+            Some(cache_file) => Ok(Some(read_cache_file(cache_file)?)),
+            None => Err(error),
+        }
+    }
+}
+
+fn read_cache_file(path: &Path) -> anyhow::Result<Value> {
+    Format::Json.parse(fs::read_to_string(path)?)
+}
@@ -0,0 +1,220 @@
+use std::path::{Path, PathBuf};
+
+use rusqlite::{params, Connection};
+
+use crate::Value;
+
+/// A SQLite database with a table of `(language, key_path, value)` rows, where the key paths
+/// are text paths like `pages.home.title` or `messages[0]`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SqliteSource {
+    path: PathBuf,
+    table: String,
+}
+
+impl SqliteSource {
+    /// Create a new SQLite source that uses the `translations` table of a database.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::SqliteSource;
+    ///
+    /// let source = SqliteSource::new("languages.db");
+    /// assert_eq!(source.get_table(), "translations");
+    /// ```
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            table: String::from("translations"),
+        }
+    }
+
+    /// Get the path of the database.
+    pub fn get_path(&self) -> PathBuf {
+        self.path.clone()
+    }
+
+    /// Get the table of the texts.
+    pub fn get_table(&self) -> String {
+        self.table.clone()
+    }
+
+    /// Change the table of the texts. The name can only have letters, digits and underscores.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::SqliteSource;
+    ///
+    /// let mut source = SqliteSource::new("languages.db");
+    /// assert!(source.set_table("app_texts").is_ok());
+    /// assert!(source.set_table("texts; DROP TABLE users").is_err());
+    /// ```
+    pub fn set_table(&mut self, table: &str) -> anyhow::Result<()> {
+        let is_valid = !table.is_empty()
+            && !table.starts_with(|c: char| c.is_ascii_digit())
+            && table.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+        if !is_valid {
+            return Err(anyhow::Error::msg(format!(
+                "The table name `{}` is not valid.",
+                table
+            )));
+        }
+
+        self.table = String::from(table);
+        Ok(())
+    }
+
+    /// Create the table of the texts if it does not exist.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::SqliteSource;
+    ///
+    /// let path = std::env::temp_dir().join("languages-rs-sqlite-create-table.db");
+    /// let _ = std::fs::remove_file(&path);
+    ///
+    /// let source = SqliteSource::new(&path);
+    /// assert!(source.create_table().is_ok());
+    /// assert!(source.discover_languages().unwrap().is_empty());
+    /// ```
+    pub fn create_table(&self) -> anyhow::Result<()> {
+        self.open()?.execute(
+            &format!(
+                "CREATE TABLE IF NOT EXISTS {} (
+                    language TEXT NOT NULL,
+                    key_path TEXT NOT NULL,
+                    value TEXT NOT NULL,
+                    PRIMARY KEY (language, key_path)
+                )",
+                self.table
+            ),
+            [],
+        )?;
+
+        Ok(())
+    }
+
+    /// Read the texts of a language, or `None` if the table does not have rows of the language.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::{SqliteSource, Value};
+    ///
+    /// let path = std::env::temp_dir().join("languages-rs-sqlite-read-language.db");
+    /// let _ = std::fs::remove_file(&path);
+    ///
+    /// let source = SqliteSource::new(&path);
+    /// assert!(source.create_table().is_ok());
+    ///
+    /// let connection = rusqlite::Connection::open(&path).unwrap();
+    /// connection
+    ///     .execute_batch(
+    ///         "INSERT INTO translations VALUES ('en', 'home.title', 'Home page');
+    ///          INSERT INTO translations VALUES ('en', 'messages[0]', 'Message 1');",
+    ///     )
+    ///     .unwrap();
+    ///
+    /// let texts = source.read_language("en").unwrap().unwrap();
+    /// assert_eq!(
+    ///     texts.get_object().unwrap().get("messages"),
+    ///     Some(&Value::Array(vec![Value::String(String::from("Message 1"))]))
+    /// );
+    ///
+    /// assert_eq!(source.read_language("es").unwrap(), None);
+    /// ```
+    pub fn read_language(&self, lang: &str) -> anyhow::Result<Option<Value>> {
+        let connection = self.open()?;
+        let mut statement = connection.prepare(&format!(
+            "SELECT key_path, value FROM {} WHERE language = ?1",
+            self.table
+        ))?;
+
+        let rows = statement
+            .query_map(params![lang], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<Vec<(String, String)>, _>>()?;
+
+        if rows.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Value::unflatten(rows)?))
+    }
+
+    /// Replace the rows of a language with the strings of the texts, in a transaction.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::{SqliteSource, Value};
+    ///
+    /// let path = std::env::temp_dir().join("languages-rs-sqlite-write-language.db");
+    /// let _ = std::fs::remove_file(&path);
+    ///
+    /// let source = SqliteSource::new(&path);
+    /// assert!(source.create_table().is_ok());
+    ///
+    /// let mut texts = HashMap::new();
+    /// texts.insert(String::from("hi"), Value::String(String::from("Hola")));
+    ///
+    /// let texts = Value::Object(texts);
+    /// assert!(source.write_language("es", &texts).is_ok());
+    /// assert_eq!(source.read_language("es").unwrap(), Some(texts));
+    /// assert_eq!(source.discover_languages().unwrap(), vec![String::from("es")]);
+    ///
+    /// // The empty arrays and objects have no rows, so they cannot be written.
+    /// let mut texts = HashMap::new();
+    /// texts.insert(String::from("days"), Value::Array(Vec::new()));
+    /// assert!(source.write_language("es", &Value::Object(texts)).is_err());
+    /// ```
+    pub fn write_language(&self, lang: &str, texts: &Value) -> anyhow::Result<()> {
+        let rows = texts.try_flatten()?;
+
+        let mut connection = self.open()?;
+        let transaction = connection.transaction()?;
+
+        transaction.execute(
+            &format!("DELETE FROM {} WHERE language = ?1", self.table),
+            params![lang],
+        )?;
+
+        for (path, text) in rows {
+            transaction.execute(
+                &format!(
+                    "INSERT INTO {} (language, key_path, value) VALUES (?1, ?2, ?3)",
+                    self.table
+                ),
+                params![lang, path, text],
+            )?;
+        }
+
+        transaction.commit()?;
+        Ok(())
+    }
+
+    /// Get the languages of the table, sorted by name.
+    pub fn discover_languages(&self) -> anyhow::Result<Vec<String>> {
+        let connection = self.open()?;
+        let mut statement = connection.prepare(&format!(
+            "SELECT DISTINCT language FROM {} ORDER BY language",
+            self.table
+        ))?;
+
+        let languages = statement
+            .query_map([], |row| row.get(0))?
+            .collect::<Result<Vec<String>, _>>()?;
+
+        Ok(languages)
+    }
+
+    fn open(&self) -> anyhow::Result<Connection> {
+        Connection::open(&self.path).map_err(|e| {
+            anyhow::Error::msg(format!(
+                "Cannot open the database `{}`: {}",
+                self.path.display(),
+                e
+            ))
+        })
+    }
+}
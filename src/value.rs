@@ -35,6 +35,14 @@
 //! ]
 //! ```
 
+#[cfg(feature = "with-android")]
+mod android;
+#[cfg(feature = "with-json")]
+mod i18next;
+#[cfg(feature = "with-ios")]
+mod ios;
+pub(crate) mod placeholders;
+
 use std::{collections::HashMap, fmt};
 
 #[cfg(feature = "with-json")]
@@ -43,6 +51,10 @@ use serde_json::Value as JsonValue;
 #[cfg(feature = "with-toml")]
 use toml::Value as TomlValue;
 
+/// The plural categories of the CLDR in their usual order.
+#[cfg(any(feature = "with-json", feature = "with-android", feature = "with-ios"))]
+pub(crate) const PLURAL_CATEGORIES: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum Value {
     String(String),
@@ -52,7 +64,7 @@ pub enum Value {
 
 impl Value {
     #[cfg(feature = "with-json")]
-    fn from_value(value: JsonValue) -> anyhow::Result<Self> {
+    fn from_json_value(value: JsonValue) -> anyhow::Result<Self> {
         if value.is_string() {
             return Ok(Self::String(String::from(value.as_str().unwrap())));
         } else if value.is_array() {
@@ -61,13 +73,13 @@ impl Value {
                     .as_array()
                     .unwrap()
                     .iter()
-                    .map(|e| Self::from_value(e.clone()).expect("Invalid format."))
-                    .collect(),
+                    .map(|e| Self::from_json_value(e.clone()))
+                    .collect::<anyhow::Result<Vec<Value>>>()?,
             ));
         } else if value.is_object() {
             let mut new_data: HashMap<String, Value> = HashMap::new();
             for (key, value) in value.as_object().unwrap().iter() {
-                new_data.insert(key.clone(), Self::from_value(value.clone())?);
+                new_data.insert(key.clone(), Self::from_json_value(value.clone())?);
             }
 
             return Ok(Self::Object(new_data));
@@ -80,7 +92,7 @@ impl Value {
     }
 
     #[cfg(feature = "with-toml")]
-    pub fn from_value(value: TomlValue) -> anyhow::Result<Self> {
+    pub fn from_toml_value(value: TomlValue) -> anyhow::Result<Self> {
         if value.is_str() {
             return Ok(Self::String(String::from(value.as_str().unwrap())));
         } else if value.is_array() {
@@ -89,13 +101,13 @@ impl Value {
                     .as_array()
                     .unwrap()
                     .iter()
-                    .map(|e| Self::from_value(e.clone()).expect("Invalid format."))
-                    .collect(),
+                    .map(|e| Self::from_toml_value(e.clone()))
+                    .collect::<anyhow::Result<Vec<Value>>>()?,
             ));
         } else if value.is_table() {
             let mut new_data: HashMap<String, Value> = HashMap::new();
             for (key, value) in value.as_table().unwrap().iter() {
-                new_data.insert(key.clone(), Self::from_value(value.clone())?);
+                new_data.insert(key.clone(), Self::from_toml_value(value.clone())?);
             }
 
             return Ok(Self::Object(new_data));
@@ -107,6 +119,95 @@ impl Value {
         )))
     }
 
+    #[cfg(all(feature = "with-toml", not(feature = "with-json")))]
+    pub fn from_value(value: TomlValue) -> anyhow::Result<Self> {
+        Self::from_toml_value(value)
+    }
+
+    #[cfg(feature = "with-json")]
+    fn to_json_value(&self) -> JsonValue {
+        match self {
+            Self::String(value) => JsonValue::String(value.clone()),
+            Self::Array(values) => {
+                JsonValue::Array(values.iter().map(|e| e.to_json_value()).collect())
+            }
+            Self::Object(data) => {
+                let mut keys: Vec<&String> = data.keys().collect();
+                keys.sort();
+
+                let mut new_data = serde_json::Map::new();
+                for key in keys {
+                    new_data.insert(key.clone(), data[key].to_json_value());
+                }
+
+                JsonValue::Object(new_data)
+            }
+        }
+    }
+
+    #[cfg(feature = "with-toml")]
+    fn to_toml_value(&self) -> TomlValue {
+        match self {
+            Self::String(value) => TomlValue::String(value.clone()),
+            Self::Array(values) => {
+                TomlValue::Array(values.iter().map(|e| e.to_toml_value()).collect())
+            }
+            Self::Object(data) => {
+                let mut keys: Vec<&String> = data.keys().collect();
+                keys.sort();
+
+                let mut new_data = toml::value::Table::new();
+                for key in keys {
+                    new_data.insert(key.clone(), data[key].to_toml_value());
+                }
+
+                TomlValue::Table(new_data)
+            }
+        }
+    }
+
+    /// Get the texts from a JSON string.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::Value;
+    ///
+    /// let value = Value::from_json_string(String::from("\"Hi\""));
+    /// assert!(value.is_ok());
+    /// assert_eq!(value.unwrap(), Value::String(String::from("Hi")));
+    ///
+    /// // The arrays can only have texts, arrays and objects.
+    /// assert!(Value::from_json_string(String::from(r#"{ "a": "Hola", "b": ["x", 1] }"#)).is_err());
+    /// ```
+    #[cfg(feature = "with-json")]
+    pub fn from_json_string(text: String) -> anyhow::Result<Self> {
+        Self::from_json_value(serde_json::from_str(&text)?)
+    }
+
+    /// Get the texts from a TOML string.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::Value;
+    ///
+    /// use std::collections::HashMap;
+    ///
+    /// let value = Value::from_toml_string(String::from("hi = \"Hi\""));
+    /// assert!(value.is_ok());
+    ///
+    /// let mut data: HashMap<String, Value> = HashMap::new();
+    /// data.insert(String::from("hi"), Value::String(String::from("Hi")));
+    ///
+    /// assert_eq!(value.unwrap(), Value::Object(data));
+    ///
+    /// // The arrays can only have texts, arrays and objects.
+    /// assert!(Value::from_toml_string(String::from("b = [\"x\", 1]")).is_err());
+    /// ```
+    #[cfg(feature = "with-toml")]
+    pub fn from_toml_string(text: String) -> anyhow::Result<Self> {
+        Self::from_toml_value(text.parse()?)
+    }
+
     /// Get the texts from a JSON string.
     ///
     /// # Example
@@ -119,7 +220,7 @@ impl Value {
     /// ```
     #[cfg(feature = "with-json")]
     pub fn from_string(text: String) -> anyhow::Result<Self> {
-        Self::from_value(serde_json::from_str(&text)?)
+        Self::from_json_string(text)
     }
 
     /// Get the texts from a JSON string or TOML string.
@@ -138,9 +239,9 @@ impl Value {
     ///
     /// assert_eq!(value.unwrap(), Value::Object(data));
     /// ```
-    #[cfg(feature = "with-toml")]
+    #[cfg(all(feature = "with-toml", not(feature = "with-json")))]
     pub fn from_string(text: String) -> anyhow::Result<Self> {
-        Self::from_value(text.parse()?)
+        Self::from_toml_string(text)
     }
 
     #[cfg(all(not(feature = "with-json"), not(feature = "with-toml")))]
@@ -148,6 +249,60 @@ impl Value {
         Err(anyhow::Error::msg("You must define the parse feature."))
     }
 
+    /// Write the texts as a pretty JSON string with the object keys sorted.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::Value;
+    ///
+    /// let mut data: HashMap<String, Value> = HashMap::new();
+    /// data.insert(String::from("title"), Value::String(String::from("Home page")));
+    /// data.insert(String::from("description"), Value::String(String::from("Hi")));
+    ///
+    /// let text = Value::Object(data).to_json_string();
+    /// assert!(text.is_ok());
+    /// assert_eq!(
+    ///     text.unwrap(),
+    ///     "{\n  \"description\": \"Hi\",\n  \"title\": \"Home page\"\n}",
+    /// );
+    /// ```
+    #[cfg(feature = "with-json")]
+    pub fn to_json_string(&self) -> anyhow::Result<String> {
+        Ok(serde_json::to_string_pretty(&self.to_json_value())?)
+    }
+
+    /// Write the texts as a TOML string with the table keys sorted.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::Value;
+    ///
+    /// let mut data: HashMap<String, Value> = HashMap::new();
+    /// data.insert(String::from("title"), Value::String(String::from("Home page")));
+    ///
+    /// let text = Value::Object(data).to_toml_string();
+    /// assert!(text.is_ok());
+    /// assert_eq!(text.unwrap(), "title = 'Home page'\n");
+    ///
+    /// // TOML files only can contain tables at the top level.
+    /// assert!(Value::String(String::from("Hi")).to_toml_string().is_err());
+    /// ```
+    #[cfg(feature = "with-toml")]
+    pub fn to_toml_string(&self) -> anyhow::Result<String> {
+        if !self.is_object() {
+            return Err(anyhow::Error::msg(format!(
+                "Cannot write `{}` as TOML, it is not an object.",
+                self
+            )));
+        }
+
+        Ok(toml::to_string_pretty(&self.to_toml_value())?)
+    }
+
     /// Check if the current value is a string.
     ///
     /// # Example
@@ -161,7 +316,7 @@ impl Value {
     ///     assert!(value.unwrap().is_string());
     /// }
     ///
-    /// #[cfg(feature = "with-toml")]
+    /// #[cfg(all(feature = "with-toml", not(feature = "with-json")))]
     /// fn main() {
     ///     let value = Value::from_string(String::from("hi = \"Hi\""));
     ///     assert!(value.is_ok());
@@ -182,6 +337,37 @@ impl Value {
         matches!(self, Self::String(_))
     }
 
+    /// Get the names of the placeholders of a string, like `name` in `Hi {name}` or `count` in
+    /// `{count, plural, one {# item} other {# items}}`, sorted and without duplicates.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::Value;
+    ///
+    /// let text = Value::String(String::from("{name} has {count, plural, one {# {kind}} other {# {kind}s}}"));
+    /// assert_eq!(text.get_placeholders(), vec!["count", "kind", "name"]);
+    ///
+    /// // The messages of the plural and select options are not placeholders.
+    /// let text = Value::String(String::from("{count, plural, one {item} other {items}}"));
+    /// assert_eq!(text.get_placeholders(), vec!["count"]);
+    ///
+    /// let text = Value::String(String::from(
+    ///     "{gender, select, male {he} female {she} other {they}} has {n, number}",
+    /// ));
+    /// assert_eq!(text.get_placeholders(), vec!["gender", "n"]);
+    ///
+    /// let text = Value::String(String::from("Use '{braces}' in {place}"));
+    /// assert_eq!(text.get_placeholders(), vec!["place"]);
+    ///
+    /// assert!(Value::Array(Vec::new()).get_placeholders().is_empty());
+    /// ```
+    pub fn get_placeholders(&self) -> Vec<String> {
+        match self {
+            Self::String(text) => placeholders::get_placeholders(text),
+            _ => Vec::new(),
+        }
+    }
+
     /// Get the string value.
     ///
     /// # Example
@@ -195,7 +381,7 @@ impl Value {
     ///     assert_eq!(value.unwrap().get_string(), Some(String::from("Hi")));
     /// }
     ///
-    /// #[cfg(feature = "with-toml")]
+    /// #[cfg(all(feature = "with-toml", not(feature = "with-json")))]
     /// fn main() {
     ///     let value = Value::from_string(String::from("hi = \"Hi\""));
     ///     assert!(value.is_ok());
@@ -232,7 +418,7 @@ impl Value {
     ///     assert!(value.unwrap().is_array());
     /// }
     ///
-    /// #[cfg(feature = "with-toml")]
+    /// #[cfg(all(feature = "with-toml", not(feature = "with-json")))]
     /// fn main() {
     ///     let value = Value::from_string(String::from("numbers = [\"1\", \"2\"]"));
     ///     assert!(value.is_ok());
@@ -269,7 +455,7 @@ impl Value {
     ///     );
     /// }
     ///
-    /// #[cfg(feature = "with-toml")]
+    /// #[cfg(all(feature = "with-toml", not(feature = "with-json")))]
     /// fn main() {
     ///     let value = Value::from_string(String::from("numbers = [\"1\", \"2\"]"));
     ///     assert!(value.is_ok());
@@ -309,7 +495,7 @@ impl Value {
     ///     assert!(value.unwrap().is_object());
     /// }
     ///
-    /// #[cfg(feature = "with-toml")]
+    /// #[cfg(all(feature = "with-toml", not(feature = "with-json")))]
     /// fn main() {
     ///     let value = Value::from_string(String::from("[home]\r\ntitle = \"Home page\""));
     ///     assert!(value.is_ok());
@@ -342,7 +528,7 @@ impl Value {
     ///     assert_eq!(value.unwrap().get_object(), Some(data));
     /// }
     ///
-    /// #[cfg(feature = "with-toml")]
+    /// #[cfg(all(feature = "with-toml", not(feature = "with-json")))]
     /// fn main() {
     ///     let value = Value::from_string(String::from("title = \"Home page\""));
     ///     assert!(value.is_ok());
@@ -362,6 +548,481 @@ impl Value {
             _ => None,
         }
     }
+
+    /// Check if the value is a plural text, an object like `{ "one": "1 item", "other": "# items" }`
+    /// with only texts, the `other` form and no keys besides the plural categories.
+    #[cfg(any(feature = "with-android", feature = "with-ios"))]
+    pub(crate) fn is_plural(&self) -> bool {
+        match self {
+            Self::Object(data) => {
+                data.contains_key("other")
+                    && data.iter().all(|(key, value)| {
+                        PLURAL_CATEGORIES.contains(&key.as_str()) && value.is_string()
+                    })
+            }
+            _ => false,
+        }
+    }
+
+    /// Get a nested value using the segments of a text path.
+    pub(crate) fn get_path(&self, path: &[PathSegment]) -> Option<&Value> {
+        let mut current = self;
+        for segment in path.iter() {
+            current = match (current, segment) {
+                (Self::Object(data), PathSegment::Key(key)) => data.get(key)?,
+                (Self::Array(values), PathSegment::Index(index)) => values.get(*index)?,
+                _ => return None,
+            };
+        }
+
+        Some(current)
+    }
+
+    /// Insert a nested value using the segments of a text path, creating the missing objects and
+    /// arrays on the way. Returns the replaced value if any, and leaves the value untouched if
+    /// the path cannot be inserted.
+    pub(crate) fn insert_path(
+        &mut self,
+        path: &[PathSegment],
+        value: Value,
+    ) -> anyhow::Result<Option<Value>> {
+        self.check_insert_path(path)?;
+
+        let (last, parents) = match path.split_last() {
+            Some(segments) => segments,
+            None => return Ok(Some(std::mem::replace(self, value))),
+        };
+
+        let mut current = self;
+        for (i, segment) in parents.iter().enumerate() {
+            let empty = match path[i + 1] {
+                PathSegment::Key(_) => Self::Object(HashMap::new()),
+                PathSegment::Index(_) => Self::Array(Vec::new()),
+            };
+
+            current = match (current, segment) {
+                (Self::Object(data), PathSegment::Key(key)) => {
+                    data.entry(key.clone()).or_insert(empty)
+                }
+                (Self::Array(values), PathSegment::Index(index)) if *index <= values.len() => {
+                    if *index == values.len() {
+                        values.push(empty);
+                    }
+
+                    &mut values[*index]
+                }
+                (_, segment) => return Err(segment.mismatch_error()),
+            };
+        }
+
+        match (current, last) {
+            (Self::Object(data), PathSegment::Key(key)) => Ok(data.insert(key.clone(), value)),
+            (Self::Array(values), PathSegment::Index(index)) if *index < values.len() => {
+                Ok(Some(std::mem::replace(&mut values[*index], value)))
+            }
+            (Self::Array(values), PathSegment::Index(index)) if *index == values.len() => {
+                values.push(value);
+                Ok(None)
+            }
+            (_, segment) => Err(segment.mismatch_error()),
+        }
+    }
+
+    /// Check that a text path can be inserted without creating the containers first, where a
+    /// missing array can only get its first item.
+    fn check_insert_path(&self, path: &[PathSegment]) -> anyhow::Result<()> {
+        let mut current = Some(self);
+        for segment in path.iter() {
+            current = match (current, segment) {
+                (Some(Self::Object(data)), PathSegment::Key(key)) => data.get(key),
+                (Some(Self::Array(values)), PathSegment::Index(index))
+                    if *index <= values.len() =>
+                {
+                    values.get(*index)
+                }
+                (None, PathSegment::Key(_)) | (None, PathSegment::Index(0)) => None,
+                (_, segment) => return Err(segment.mismatch_error()),
+            };
+        }
+
+        Ok(())
+    }
+
+    /// Merge another value into this one, where the objects are merged key by key and any other
+    /// value is replaced. Returns the paths of the texts that come from the other value.
+    pub(crate) fn merge(&mut self, other: Value) -> Vec<String> {
+        let mut paths = Vec::new();
+        self.merge_at(other, &[], &mut paths);
+
+        paths
+    }
+
+    fn merge_at(&mut self, other: Value, path: &[PathSegment], paths: &mut Vec<String>) {
+        match (self, other) {
+            (Self::Object(data), Self::Object(other_data)) => {
+                for (key, value) in other_data {
+                    let mut key_path = path.to_vec();
+                    key_path.push(PathSegment::Key(key.clone()));
+
+                    match data.get_mut(&key) {
+                        Some(current) => current.merge_at(value, &key_path, paths),
+                        None => {
+                            value.collect_text_paths(&key_path, paths);
+                            data.insert(key, value);
+                        }
+                    }
+                }
+            }
+            (current, other) => {
+                other.collect_text_paths(path, paths);
+                *current = other;
+            }
+        }
+    }
+
+    /// Get the paths of the texts, where the strings and the arrays are the texts and the objects
+    /// are followed key by key.
+    pub(crate) fn get_text_paths(&self) -> Vec<String> {
+        let mut paths = Vec::new();
+        self.collect_text_paths(&[], &mut paths);
+
+        paths
+    }
+
+    fn collect_text_paths(&self, path: &[PathSegment], paths: &mut Vec<String>) {
+        match self {
+            Self::Object(data) => {
+                for (key, value) in data.iter() {
+                    let mut key_path = path.to_vec();
+                    key_path.push(PathSegment::Key(key.clone()));
+
+                    value.collect_text_paths(&key_path, paths);
+                }
+            }
+            _ => paths.push(PathSegment::join(path)),
+        }
+    }
+
+    /// Remove a nested value using the segments of a text path.
+    pub(crate) fn remove_path(&mut self, path: &[PathSegment]) -> Option<Value> {
+        let (last, parents) = path.split_last()?;
+
+        let mut current = self;
+        for segment in parents.iter() {
+            current = match (current, segment) {
+                (Self::Object(data), PathSegment::Key(key)) => data.get_mut(key)?,
+                (Self::Array(values), PathSegment::Index(index)) => values.get_mut(*index)?,
+                _ => return None,
+            };
+        }
+
+        match (current, last) {
+            (Self::Object(data), PathSegment::Key(key)) => data.remove(key),
+            (Self::Array(values), PathSegment::Index(index)) if *index < values.len() => {
+                Some(values.remove(*index))
+            }
+            _ => None,
+        }
+    }
+
+    /// Get every string with its full text path, sorted by path, like
+    /// `("messages[0]", "Message 1")`. The dots, brackets and backslashes of the keys are escaped
+    /// with a backslash, like `loading\.\.\.` for the `loading...` key.
+    ///
+    /// The empty objects and arrays do not have strings, so they are not in the list and
+    /// `Value::unflatten` does not create them again. The texts with empty keys are not in the
+    /// list either, because their paths cannot be written. Use `Value::try_flatten` to get an
+    /// error instead of losing them.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::Value;
+    ///
+    /// let mut texts = HashMap::new();
+    /// texts.insert(String::from("hi"), Value::String(String::from("Hi")));
+    /// texts.insert(
+    ///     String::from("messages"),
+    ///     Value::Array(vec![Value::String(String::from("Message 1"))]),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Value::Object(texts).flatten(),
+    ///     vec![
+    ///         (String::from("hi"), String::from("Hi")),
+    ///         (String::from("messages[0]"), String::from("Message 1")),
+    ///     ]
+    /// );
+    ///
+    /// let mut texts = HashMap::new();
+    /// texts.insert(String::from("Loading..."), Value::String(String::from("Cargando...")));
+    /// texts.insert(String::from("a.b"), Value::String(String::from("A")));
+    ///
+    /// let texts = Value::Object(texts);
+    /// assert_eq!(
+    ///     texts.flatten(),
+    ///     vec![
+    ///         (String::from("Loading\\.\\.\\."), String::from("Cargando...")),
+    ///         (String::from("a\\.b"), String::from("A")),
+    ///     ]
+    /// );
+    /// assert_eq!(Value::unflatten(texts.flatten()).unwrap(), texts);
+    /// ```
+    pub fn flatten(&self) -> Vec<(String, String)> {
+        let mut strings = Vec::new();
+        self.collect_strings(&mut Vec::new(), &mut strings);
+
+        strings.sort_by(|(a, _), (b, _)| a.cmp(b));
+        strings
+            .into_iter()
+            .map(|(path, text)| (PathSegment::join(&path), text))
+            .collect()
+    }
+
+    /// Get every string with its full text path like `flatten`, or an error if the texts have
+    /// empty keys or empty objects and arrays, which cannot be written as text paths.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::Value;
+    ///
+    /// let mut texts = HashMap::new();
+    /// texts.insert(String::from("hi"), Value::String(String::from("Hi")));
+    /// assert_eq!(
+    ///     Value::Object(texts.clone()).try_flatten().unwrap(),
+    ///     vec![(String::from("hi"), String::from("Hi"))]
+    /// );
+    ///
+    /// texts.insert(String::from("messages"), Value::Array(Vec::new()));
+    /// assert!(Value::Object(texts).try_flatten().is_err());
+    /// ```
+    pub fn try_flatten(&self) -> anyhow::Result<Vec<(String, String)>> {
+        self.check_flatten(&mut Vec::new())?;
+        Ok(self.flatten())
+    }
+
+    /// Check that every container has values and every key is not empty.
+    fn check_flatten(&self, path: &mut Vec<PathSegment>) -> anyhow::Result<()> {
+        let is_empty = match self {
+            Self::String(_) => return Ok(()),
+            Self::Array(values) => values.is_empty(),
+            Self::Object(data) => data.is_empty(),
+        };
+
+        if is_empty && !path.is_empty() {
+            return Err(anyhow::Error::msg(format!(
+                "The text `{}` is empty and cannot be written as a text path.",
+                PathSegment::join(path)
+            )));
+        }
+
+        match self {
+            Self::Array(values) => {
+                for (index, value) in values.iter().enumerate() {
+                    path.push(PathSegment::Index(index));
+                    value.check_flatten(path)?;
+                    path.pop();
+                }
+            }
+            Self::Object(data) => {
+                for (key, value) in data.iter() {
+                    if key.is_empty() {
+                        return Err(anyhow::Error::msg(format!(
+                            "The object `{}` has an empty key, which cannot be written as a text path.",
+                            PathSegment::join(path)
+                        )));
+                    }
+
+                    path.push(PathSegment::Key(key.clone()));
+                    value.check_flatten(path)?;
+                    path.pop();
+                }
+            }
+            Self::String(_) => {}
+        }
+
+        Ok(())
+    }
+
+    fn collect_strings(
+        &self,
+        path: &mut Vec<PathSegment>,
+        strings: &mut Vec<(Vec<PathSegment>, String)>,
+    ) {
+        match self {
+            Self::String(text) => strings.push((path.clone(), text.clone())),
+            Self::Array(values) => {
+                for (index, value) in values.iter().enumerate() {
+                    path.push(PathSegment::Index(index));
+                    value.collect_strings(path, strings);
+                    path.pop();
+                }
+            }
+            Self::Object(data) => {
+                for (key, value) in data.iter().filter(|(key, _)| !key.is_empty()) {
+                    path.push(PathSegment::Key(key.clone()));
+                    value.collect_strings(path, strings);
+                    path.pop();
+                }
+            }
+        }
+    }
+
+    /// Create an object from strings with their full text paths, the opposite of `flatten`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::Value;
+    ///
+    /// let texts = Value::unflatten(vec![
+    ///     (String::from("messages[1]"), String::from("Message 2")),
+    ///     (String::from("messages[0]"), String::from("Message 1")),
+    /// ])
+    /// .unwrap();
+    ///
+    /// assert_eq!(
+    ///     texts.get_object().unwrap().get("messages"),
+    ///     Some(&Value::Array(vec![
+    ///         Value::String(String::from("Message 1")),
+    ///         Value::String(String::from("Message 2")),
+    ///     ]))
+    /// );
+    ///
+    /// assert!(Value::unflatten(vec![(String::from("messages[1]"), String::from("Message 2"))]).is_err());
+    /// ```
+    pub fn unflatten(strings: Vec<(String, String)>) -> anyhow::Result<Self> {
+        let mut paths = Vec::new();
+        for (path, text) in strings {
+            paths.push((PathSegment::parse(&path)?, text));
+        }
+
+        // The array items must be inserted in order.
+        paths.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut value = Self::Object(HashMap::new());
+        for (path, text) in paths {
+            value.insert_path(&path, Self::String(text))?;
+        }
+
+        Ok(value)
+    }
+}
+
+/// A segment of a text path like `pages.home.title` or `messages[0]`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+impl PathSegment {
+    /// Parse a text path, where the object keys are separated by dots, the array indexes are
+    /// written between brackets and the dots, brackets and backslashes of the keys are escaped
+    /// with a backslash, like `pages.loading\.\.\.` for the `loading...` key.
+    pub(crate) fn parse(path: &str) -> anyhow::Result<Vec<Self>> {
+        let invalid = || anyhow::Error::msg(format!("The text path `{}` is not valid.", path));
+
+        let mut segments = Vec::new();
+        let mut chars = path.chars();
+        loop {
+            // Read a key until the dot or bracket that ends it.
+            let mut key = String::new();
+            let mut end = None;
+            while let Some(c) = chars.next() {
+                match c {
+                    '\\' => key.push(chars.next().ok_or_else(invalid)?),
+                    '.' | '[' => {
+                        end = Some(c);
+                        break;
+                    }
+                    ']' => return Err(invalid()),
+                    c => key.push(c),
+                }
+            }
+
+            if key.is_empty() {
+                return Err(invalid());
+            }
+
+            segments.push(Self::Key(key));
+
+            while end == Some('[') {
+                let mut index = String::new();
+                loop {
+                    match chars.next() {
+                        Some(']') => break,
+                        Some(c) => index.push(c),
+                        None => return Err(invalid()),
+                    }
+                }
+
+                segments.push(Self::Index(index.parse().map_err(|_| invalid())?));
+
+                end = chars.next();
+                if !matches!(end, None | Some('.') | Some('[')) {
+                    return Err(invalid());
+                }
+            }
+
+            if end.is_none() {
+                return Ok(segments);
+            }
+        }
+    }
+
+    /// Write the segments as a text path, escaping the dots, brackets and backslashes of the
+    /// keys.
+    pub(crate) fn join(path: &[Self]) -> String {
+        let mut text = String::new();
+        for (i, segment) in path.iter().enumerate() {
+            match segment {
+                Self::Key(key) => {
+                    if i > 0 {
+                        text.push('.');
+                    }
+
+                    for c in key.chars() {
+                        if matches!(c, '.' | '[' | ']' | '\\') {
+                            text.push('\\');
+                        }
+
+                        text.push(c);
+                    }
+                }
+                Self::Index(index) => text.push_str(&format!("[{}]", index)),
+            }
+        }
+
+        text
+    }
+
+    /// Check if a text path is the same, a parent or a child of another text path.
+    pub(crate) fn is_related(path: &str, other: &str) -> bool {
+        let is_child = |child: &str, parent: &str| {
+            child.starts_with(parent)
+                && (child.len() == parent.len()
+                    || child[parent.len()..].starts_with('.')
+                    || child[parent.len()..].starts_with('['))
+        };
+
+        is_child(path, other) || is_child(other, path)
+    }
+
+    fn mismatch_error(&self) -> anyhow::Error {
+        match self {
+            Self::Key(key) => anyhow::Error::msg(format!(
+                "Cannot use the key `{}`, the text is not an object.",
+                key
+            )),
+            Self::Index(index) => anyhow::Error::msg(format!(
+                "Cannot use the index `{}`, the text is not an array or it is out of bounds.",
+                index
+            )),
+        }
+    }
 }
 
 impl fmt::Display for Value {
@@ -0,0 +1,330 @@
+use std::collections::HashMap;
+
+use roxmltree::{Document, Node};
+
+use super::{PathSegment, Value, PLURAL_CATEGORIES};
+
+impl Value {
+    /// Write the texts as an Android `strings.xml` file, where the text paths are the names of
+    /// the resources, the arrays are `string-array` resources and the plural objects, like
+    /// `{ "one": "%d item", "other": "%d items" }`, are `plurals` resources.
+    ///
+    /// The placeholders are not changed, so the texts must use the Android placeholders, like
+    /// `%1$s`, to be formatted by Android.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::Value;
+    ///
+    /// let mut items = HashMap::new();
+    /// items.insert(String::from("one"), Value::String(String::from("%d item")));
+    /// items.insert(String::from("other"), Value::String(String::from("%d items")));
+    ///
+    /// let mut home = HashMap::new();
+    /// home.insert(String::from("title"), Value::String(String::from("Tom's home")));
+    ///
+    /// let mut data = HashMap::new();
+    /// data.insert(String::from("home"), Value::Object(home));
+    /// data.insert(String::from("items"), Value::Object(items));
+    /// data.insert(
+    ///     String::from("days"),
+    ///     Value::Array(vec![
+    ///         Value::String(String::from("Monday")),
+    ///         Value::String(String::from("Tuesday")),
+    ///     ]),
+    /// );
+    ///
+    /// let texts = Value::Object(data);
+    /// assert_eq!(
+    ///     texts.to_android_xml().unwrap(),
+    ///     r#"<?xml version="1.0" encoding="utf-8"?>
+    /// <resources>
+    ///     <string-array name="days">
+    ///         <item>Monday</item>
+    ///         <item>Tuesday</item>
+    ///     </string-array>
+    ///     <string name="home.title">Tom\'s home</string>
+    ///     <plurals name="items">
+    ///         <item quantity="one">%d item</item>
+    ///         <item quantity="other">%d items</item>
+    ///     </plurals>
+    /// </resources>
+    /// "#
+    /// );
+    ///
+    /// assert_eq!(Value::from_android_xml(texts.to_android_xml().unwrap()).unwrap(), texts);
+    /// ```
+    pub fn to_android_xml(&self) -> anyhow::Result<String> {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<resources>\n");
+        match self {
+            Self::Object(_) => write_resources(self, &mut Vec::new(), &mut xml)?,
+            _ => {
+                return Err(anyhow::Error::msg(format!(
+                    "Cannot write `{}` as Android resources, it is not an object.",
+                    self
+                )))
+            }
+        }
+
+        xml.push_str("</resources>\n");
+        Ok(xml)
+    }
+
+    /// Read the `string`, `string-array` and `plurals` resources of an Android `strings.xml`
+    /// file, where the dots of the names are nested objects.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::Value;
+    ///
+    /// let texts = Value::from_android_xml(String::from(
+    ///     r#"<?xml version="1.0" encoding="utf-8"?>
+    ///     <resources>
+    ///         <string name="app_name">My app</string>
+    ///         <string name="home.title">\"Quoted\" &amp; escaped\nText</string>
+    ///         <plurals name="items">
+    ///             <item quantity="one">%d item</item>
+    ///             <item quantity="other">%d items</item>
+    ///         </plurals>
+    ///     </resources>"#,
+    /// ))
+    /// .unwrap();
+    ///
+    /// assert_eq!(
+    ///     texts.get_object().unwrap().get("app_name"),
+    ///     Some(&Value::String(String::from("My app")))
+    /// );
+    ///
+    /// let home = texts.get_object().unwrap()["home"].get_object().unwrap();
+    /// assert_eq!(
+    ///     home.get("title"),
+    ///     Some(&Value::String(String::from("\"Quoted\" & escaped\nText")))
+    /// );
+    ///
+    /// let items = texts.get_object().unwrap()["items"].get_object().unwrap();
+    /// assert_eq!(
+    ///     items.get("other"),
+    ///     Some(&Value::String(String::from("%d items")))
+    /// );
+    /// ```
+    pub fn from_android_xml(text: String) -> anyhow::Result<Self> {
+        let document = Document::parse(&text).map_err(|e| {
+            anyhow::Error::msg(format!("Cannot parse the Android resources: {}", e))
+        })?;
+
+        let root = document.root_element();
+        if root.tag_name().name() != "resources" {
+            return Err(anyhow::Error::msg(format!(
+                "The root element must be `resources`, but it is `{}`.",
+                root.tag_name().name()
+            )));
+        }
+
+        let mut texts = Self::Object(HashMap::new());
+        for node in root.children().filter(|node| node.is_element()) {
+            let value = match node.tag_name().name() {
+                "string" => Self::String(get_text(node)),
+                "string-array" => Self::Array(
+                    get_items(node)
+                        .map(|item| Self::String(get_text(item)))
+                        .collect(),
+                ),
+                "plurals" => {
+                    let mut forms = HashMap::new();
+                    for item in get_items(node) {
+                        if let Some(quantity) = item.attribute("quantity") {
+                            forms.insert(String::from(quantity), Self::String(get_text(item)));
+                        }
+                    }
+
+                    Self::Object(forms)
+                }
+                _ => continue,
+            };
+
+            let name = match node.attribute("name") {
+                Some(name) => name,
+                None => {
+                    return Err(anyhow::Error::msg(format!(
+                        "The `{}` resource does not have a name.",
+                        node.tag_name().name()
+                    )))
+                }
+            };
+
+            let path = name
+                .split('.')
+                .map(|key| PathSegment::Key(String::from(key)))
+                .collect::<Vec<PathSegment>>();
+
+            texts.insert_path(&path, value).map_err(|e| {
+                anyhow::Error::msg(format!("Cannot read the resource `{}`: {}", name, e))
+            })?;
+        }
+
+        Ok(texts)
+    }
+}
+
+fn write_resources(
+    value: &Value,
+    path: &mut Vec<PathSegment>,
+    xml: &mut String,
+) -> anyhow::Result<()> {
+    let data = match value {
+        Value::Object(data) if path.is_empty() || !value.is_plural() => data,
+        _ => return write_resource(value, path, xml),
+    };
+
+    let mut keys: Vec<&String> = data.keys().collect();
+    keys.sort();
+
+    for key in keys {
+        // The names of the resources can only have letters, digits, underscores and the dots
+        // between the keys.
+        let is_valid = !key.starts_with(|c: char| c.is_ascii_digit())
+            && !key.is_empty()
+            && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+        path.push(PathSegment::Key(key.clone()));
+        if !is_valid {
+            return Err(anyhow::Error::msg(format!(
+                "Cannot write `{}` as an Android resource, the names can only have letters, digits and underscores.",
+                PathSegment::join(path)
+            )));
+        }
+
+        write_resources(&data[key], path, xml)?;
+        path.pop();
+    }
+
+    Ok(())
+}
+
+fn write_resource(value: &Value, path: &[PathSegment], xml: &mut String) -> anyhow::Result<()> {
+    let name = PathSegment::join(path);
+    match value {
+        Value::String(text) => xml.push_str(&format!(
+            "    <string name=\"{}\">{}</string>\n",
+            name,
+            escape(text)
+        )),
+        Value::Array(values) => {
+            xml.push_str(&format!("    <string-array name=\"{}\">\n", name));
+            for value in values.iter() {
+                match value {
+                    Value::String(text) => {
+                        xml.push_str(&format!("        <item>{}</item>\n", escape(text)))
+                    }
+                    _ => {
+                        return Err(anyhow::Error::msg(format!(
+                        "Cannot write `{}` as an Android resource, the arrays can only have texts.",
+                        name
+                    )))
+                    }
+                }
+            }
+
+            xml.push_str("    </string-array>\n");
+        }
+        Value::Object(forms) => {
+            xml.push_str(&format!("    <plurals name=\"{}\">\n", name));
+            for category in PLURAL_CATEGORIES.iter() {
+                if let Some(Value::String(text)) = forms.get(*category) {
+                    xml.push_str(&format!(
+                        "        <item quantity=\"{}\">{}</item>\n",
+                        category,
+                        escape(text)
+                    ));
+                }
+            }
+
+            xml.push_str("    </plurals>\n");
+        }
+    }
+
+    Ok(())
+}
+
+fn get_items<'a, 'input: 'a>(node: Node<'a, 'input>) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children()
+        .filter(|child| child.is_element() && child.tag_name().name() == "item")
+}
+
+/// Get the text of a resource without the Android escapes, including the texts of the inline
+/// elements like `<b>`.
+fn get_text(node: Node) -> String {
+    let raw: String = node
+        .descendants()
+        .filter(|node| node.is_text())
+        .filter_map(|node| node.text())
+        .collect();
+
+    // Android keeps the whitespaces of the texts between double quotes and collapses the
+    // whitespaces of the other texts.
+    let raw = if raw.len() >= 2 && raw.starts_with('"') && raw.ends_with('"') {
+        String::from(&raw[1..raw.len() - 1])
+    } else {
+        raw.split_whitespace().collect::<Vec<&str>>().join(" ")
+    };
+
+    let mut text = String::new();
+    let mut chars = raw.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            text.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => text.push('\n'),
+            Some('t') => text.push('\t'),
+            Some('u') => {
+                let code: String = chars.by_ref().take(4).collect();
+                if let Some(c) = u32::from_str_radix(&code, 16).ok().and_then(char::from_u32) {
+                    text.push(c);
+                }
+            }
+            Some(c) => text.push(c),
+            None => {}
+        }
+    }
+
+    text
+}
+
+/// Escape a text for the Android resources and XML, between double quotes if its whitespaces
+/// would be collapsed.
+fn escape(text: &str) -> String {
+    let is_quoted = text.starts_with(char::is_whitespace)
+        || text.ends_with(char::is_whitespace)
+        || text.contains("  ");
+
+    let mut escaped = String::with_capacity(text.len());
+    for (i, c) in text.chars().enumerate() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\'' => escaped.push_str("\\'"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            // Android reads the texts that start with `@` or `?` as references.
+            '@' | '?' if i == 0 => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            c => escaped.push(c),
+        }
+    }
+
+    if is_quoted {
+        format!("\"{}\"", escaped)
+    } else {
+        escaped
+    }
+}
@@ -0,0 +1,288 @@
+use std::collections::HashMap;
+
+use super::{PathSegment, Value, PLURAL_CATEGORIES};
+
+impl Value {
+    /// Parse the texts of an i18next v4 JSON file, using the ICU syntax of the other texts:
+    ///
+    /// - The interpolations like `{{name}}`, `{{- name}}` or `{{date, datetime}}` are
+    ///   placeholders like `{name}`.
+    /// - The plural texts like `items_one` and `items_other` are an ICU plural text like
+    ///   `{count, plural, one {...} other {...}}` with the `items` key, and the ordinal texts like
+    ///   `place_ordinal_one` are an ICU `selectordinal` text.
+    /// - The nested texts like `$t(common.app)` are replaced with their texts, so they must be in
+    ///   the same file. The options of the nested texts are ignored.
+    ///
+    /// The i18next files are not a `Format` of the sources, since they use the `.json` extension
+    /// like the other `JSON` files, so `load` reads them as plain `JSON` texts without these
+    /// conversions. They are read on their own and their texts are added with
+    /// `Languages::add_language`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::{LanguageTexts, Value};
+    ///
+    /// let texts = Value::from_i18next_string(String::from(
+    ///     r#"{
+    ///         "app": "My app",
+    ///         "welcome": "Welcome to $t(app), {{name}}!",
+    ///         "items_one": "{{count}} item",
+    ///         "items_other": "{{count}} items",
+    ///         "pages": {
+    ///             "home": "Home of {{- user.name}}"
+    ///         }
+    ///     }"#,
+    /// ))
+    /// .unwrap();
+    ///
+    /// let texts = LanguageTexts::new(String::from("en"), texts).unwrap();
+    /// assert_eq!(
+    ///     texts.try_get_text("welcome"),
+    ///     Some(Value::String(String::from("Welcome to My app, {name}!")))
+    /// );
+    /// assert_eq!(
+    ///     texts.try_get_text("items"),
+    ///     Some(Value::String(String::from(
+    ///         "{count, plural, one {{count} item} other {{count} items}}"
+    ///     )))
+    /// );
+    /// assert_eq!(
+    ///     texts.try_get_path("pages.home"),
+    ///     Some(Value::String(String::from("Home of {user.name}")))
+    /// );
+    ///
+    /// assert!(Value::from_i18next_string(String::from(r#"{ "a": "$t(b)", "b": "$t(a)" }"#)).is_err());
+    /// ```
+    pub fn from_i18next_string(text: String) -> anyhow::Result<Self> {
+        let value = Self::from_json_string(text)?;
+        if !value.is_object() {
+            return Err(anyhow::Error::msg(format!(
+                "The i18next texts must be an object, but they are `{}`.",
+                value
+            )));
+        }
+
+        let texts = convert_texts(&value, &mut Vec::new())?;
+        resolve_nesting(&texts, &texts, &mut Vec::new())
+    }
+}
+
+/// Convert the interpolations and plural texts of i18next to the ICU syntax, keeping the nested
+/// texts like `$t(key)`.
+fn convert_texts(value: &Value, path: &mut Vec<PathSegment>) -> anyhow::Result<Value> {
+    let data = match value {
+        Value::String(text) => return Ok(Value::String(convert_text(text))),
+        Value::Array(values) => {
+            let mut converted = Vec::new();
+            for (index, value) in values.iter().enumerate() {
+                path.push(PathSegment::Index(index));
+                converted.push(convert_texts(value, path)?);
+                path.pop();
+            }
+
+            return Ok(Value::Array(converted));
+        }
+        Value::Object(data) => data,
+    };
+
+    // Group the plural texts like `items_one` by their key and kind.
+    let mut plurals: HashMap<String, (&str, HashMap<&str, &String>)> = HashMap::new();
+    let mut converted = HashMap::new();
+    for (key, value) in data.iter() {
+        if let (Some((base, kind, category)), Value::String(text)) = (split_plural(key), value) {
+            let has_other = data
+                .get(&format!("{}_{}other", base, kind))
+                .is_some_and(|other| other.is_string());
+
+            if has_other {
+                let (group_kind, forms) = plurals
+                    .entry(String::from(base))
+                    .or_insert_with(|| (kind, HashMap::new()));
+
+                if *group_kind != kind {
+                    path.push(PathSegment::Key(String::from(base)));
+                    return Err(anyhow::Error::msg(format!(
+                        "The text `{}` has both cardinal and ordinal plural forms.",
+                        PathSegment::join(path)
+                    )));
+                }
+
+                forms.insert(category, text);
+                continue;
+            }
+        }
+
+        path.push(PathSegment::Key(key.clone()));
+        converted.insert(key.clone(), convert_texts(value, path)?);
+        path.pop();
+    }
+
+    for (base, (kind, forms)) in plurals {
+        if converted.contains_key(&base) {
+            path.push(PathSegment::Key(base));
+            return Err(anyhow::Error::msg(format!(
+                "The text `{}` has both a text and plural forms.",
+                PathSegment::join(path)
+            )));
+        }
+
+        let mut options = Vec::new();
+        for category in PLURAL_CATEGORIES.iter() {
+            if let Some(text) = forms.get(category) {
+                options.push(format!("{} {{{}}}", category, convert_text(text)));
+            }
+        }
+
+        let argument = if kind.is_empty() {
+            "plural"
+        } else {
+            "selectordinal"
+        };
+
+        converted.insert(
+            base,
+            Value::String(format!("{{count, {}, {}}}", argument, options.join(" "))),
+        );
+    }
+
+    Ok(Value::Object(converted))
+}
+
+/// Split a plural key like `items_one` or `place_ordinal_two` in its base key, its kind (empty
+/// or `ordinal_`) and its plural category.
+fn split_plural(key: &str) -> Option<(&str, &'static str, &'static str)> {
+    PLURAL_CATEGORIES.iter().find_map(|category| {
+        let base = key.strip_suffix(category)?.strip_suffix('_')?;
+        let (base, kind) = match base.strip_suffix("_ordinal") {
+            Some(base) => (base, "ordinal_"),
+            None => (base, ""),
+        };
+
+        if base.is_empty() {
+            None
+        } else {
+            Some((base, kind, *category))
+        }
+    })
+}
+
+/// Convert the interpolations of an i18next text like `{{name}}` to placeholders like `{name}`,
+/// quoting the other braces, and remove the options of the nested texts.
+fn convert_text(text: &str) -> String {
+    let mut converted = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        if let Some(inner) = rest.strip_prefix("{{") {
+            if let Some(end) = inner.find("}}") {
+                // Remove the unescape flag and the format, like `{{- name}}` or
+                // `{{date, datetime}}`.
+                let name = inner[..end].trim().trim_start_matches('-');
+                let name = name.split(',').next().unwrap_or_default().trim();
+                converted.push_str(&format!("{{{}}}", name));
+                rest = &inner[end + 2..];
+                continue;
+            }
+        }
+
+        if let Some(inner) = rest.strip_prefix("$t(") {
+            if let Some(end) = find_nesting_end(inner) {
+                let key = inner[..end].split(',').next().unwrap_or_default().trim();
+                converted.push_str(&format!("$t({})", key));
+                rest = &inner[end + 1..];
+                continue;
+            }
+        }
+
+        match c {
+            '{' | '}' => converted.push_str(&format!("'{}'", c)),
+            // An apostrophe before a brace would start an ICU quoted text.
+            '\'' if rest[1..].starts_with(['{', '}']) => converted.push_str("''"),
+            c => converted.push(c),
+        }
+
+        rest = &rest[c.len_utf8()..];
+    }
+
+    converted
+}
+
+/// Find the `)` that closes a nested text, skipping the braces of its options.
+fn find_nesting_end(text: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in text.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            ')' if depth <= 0 => return Some(i),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Replace the nested texts like `$t(key)` with their texts.
+fn resolve_nesting(texts: &Value, value: &Value, stack: &mut Vec<String>) -> anyhow::Result<Value> {
+    match value {
+        Value::String(text) => Ok(Value::String(resolve_text(texts, text, stack)?)),
+        Value::Array(values) => Ok(Value::Array(
+            values
+                .iter()
+                .map(|value| resolve_nesting(texts, value, stack))
+                .collect::<anyhow::Result<Vec<Value>>>()?,
+        )),
+        Value::Object(data) => {
+            let mut resolved = HashMap::new();
+            for (key, value) in data.iter() {
+                resolved.insert(key.clone(), resolve_nesting(texts, value, stack)?);
+            }
+
+            Ok(Value::Object(resolved))
+        }
+    }
+}
+
+fn resolve_text(texts: &Value, text: &str, stack: &mut Vec<String>) -> anyhow::Result<String> {
+    let mut resolved = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("$t(") {
+        let end = match rest[start..].find(')') {
+            Some(end) => start + end,
+            None => break,
+        };
+
+        let key = &rest[start + 3..end];
+        if stack.iter().any(|nested| nested == key) {
+            return Err(anyhow::Error::msg(format!(
+                "The nested text `{}` includes itself.",
+                key
+            )));
+        }
+
+        let path = key
+            .split('.')
+            .map(|key| PathSegment::Key(String::from(key)))
+            .collect::<Vec<PathSegment>>();
+
+        let nested = match texts.get_path(&path) {
+            Some(Value::String(nested)) => nested,
+            _ => {
+                return Err(anyhow::Error::msg(format!(
+                    "Cannot find the nested text `{}`.",
+                    key
+                )))
+            }
+        };
+
+        stack.push(String::from(key));
+        let nested = resolve_text(texts, nested, stack)?;
+        stack.pop();
+
+        resolved.push_str(&rest[..start]);
+        resolved.push_str(&nested);
+        rest = &rest[end + 1..];
+    }
+
+    resolved.push_str(rest);
+    Ok(resolved)
+}
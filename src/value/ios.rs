@@ -0,0 +1,594 @@
+use std::collections::HashMap;
+
+use roxmltree::{Document, Node, ParsingOptions};
+
+use super::{PathSegment, Value, PLURAL_CATEGORIES};
+
+/// The comment of the files written by languages-rs, whose keys are read as text paths.
+const TEXT_PATHS_COMMENT: &str = "The keys are the text paths of languages-rs.";
+
+impl Value {
+    /// Write the texts as an iOS `Localizable.strings` file, where the keys are the text paths
+    /// like `home.title` or `days[0]`. The plural objects are not written, see
+    /// `Value::to_ios_stringsdict`.
+    ///
+    /// The file starts with a comment to read its keys as text paths again with
+    /// `Value::from_ios_strings`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::Value;
+    ///
+    /// let mut home = HashMap::new();
+    /// home.insert(String::from("title"), Value::String(String::from("The \"home\" page")));
+    ///
+    /// let mut data = HashMap::new();
+    /// data.insert(String::from("home"), Value::Object(home));
+    /// data.insert(
+    ///     String::from("days"),
+    ///     Value::Array(vec![
+    ///         Value::String(String::from("Monday")),
+    ///         Value::String(String::from("Tuesday")),
+    ///     ]),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Value::Object(data.clone()).to_ios_strings().unwrap(),
+    ///     r#"/* The keys are the text paths of languages-rs. */
+    /// "days[0]" = "Monday";
+    /// "days[1]" = "Tuesday";
+    /// "home.title" = "The \"home\" page";
+    /// "#
+    /// );
+    ///
+    /// let strings = Value::Object(data.clone()).to_ios_strings().unwrap();
+    /// assert_eq!(Value::from_ios_strings(strings, None).unwrap(), Value::Object(data));
+    /// ```
+    pub fn to_ios_strings(&self) -> anyhow::Result<String> {
+        let (strings, _) = self.get_ios_entries()?;
+
+        let mut text = format!("/* {} */\n", TEXT_PATHS_COMMENT);
+        for (path, value) in strings {
+            text.push_str(&format!("\"{}\" = \"{}\";\n", escape(&path), escape(value)));
+        }
+
+        Ok(text)
+    }
+
+    /// Write the plural objects of the texts, like `{ "one": "%d item", "other": "%d items" }`,
+    /// as an iOS `Localizable.stringsdict` file.
+    ///
+    /// The type of the number is taken from the first placeholder of the `other` form, like `d`
+    /// in `%d items` or `lld` in `%lld items`, and is `d` if there is no placeholder.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::Value;
+    ///
+    /// let mut items = HashMap::new();
+    /// items.insert(String::from("one"), Value::String(String::from("%lld item")));
+    /// items.insert(String::from("other"), Value::String(String::from("%lld items")));
+    ///
+    /// let mut data = HashMap::new();
+    /// data.insert(String::from("items"), Value::Object(items));
+    /// data.insert(String::from("title"), Value::String(String::from("Items")));
+    ///
+    /// let texts = Value::Object(data);
+    /// assert!(texts
+    ///     .to_ios_strings()
+    ///     .unwrap()
+    ///     .ends_with("\n\"title\" = \"Items\";\n"));
+    /// assert_eq!(
+    ///     texts.to_ios_stringsdict().unwrap(),
+    ///     r#"<?xml version="1.0" encoding="UTF-8"?>
+    /// <!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+    /// <plist version="1.0">
+    /// <!-- The keys are the text paths of languages-rs. -->
+    /// <dict>
+    ///     <key>items</key>
+    ///     <dict>
+    ///         <key>NSStringLocalizedFormatKey</key>
+    ///         <string>%#@value@</string>
+    ///         <key>value</key>
+    ///         <dict>
+    ///             <key>NSStringFormatSpecTypeKey</key>
+    ///             <string>NSStringPluralRuleType</string>
+    ///             <key>NSStringFormatValueTypeKey</key>
+    ///             <string>lld</string>
+    ///             <key>one</key>
+    ///             <string>%lld item</string>
+    ///             <key>other</key>
+    ///             <string>%lld items</string>
+    ///         </dict>
+    ///     </dict>
+    /// </dict>
+    /// </plist>
+    /// "#
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Value::from_ios_strings(
+    ///         texts.to_ios_strings().unwrap(),
+    ///         Some(texts.to_ios_stringsdict().unwrap())
+    ///     )
+    ///     .unwrap(),
+    ///     texts
+    /// );
+    /// ```
+    pub fn to_ios_stringsdict(&self) -> anyhow::Result<String> {
+        let (_, plurals) = self.get_ios_entries()?;
+
+        let mut xml = String::from(concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n",
+            "<plist version=\"1.0\">\n"
+        ));
+        xml.push_str(&format!("<!-- {} -->\n<dict>\n", TEXT_PATHS_COMMENT));
+
+        for (path, forms) in plurals {
+            let value_type = match forms.get("other") {
+                Some(Self::String(text)) => get_value_type(text),
+                _ => String::from("d"),
+            };
+
+            xml.push_str(&format!(
+                "    <key>{}</key>\n    <dict>\n",
+                escape_xml(&path)
+            ));
+            xml.push_str("        <key>NSStringLocalizedFormatKey</key>\n");
+            xml.push_str("        <string>%#@value@</string>\n");
+            xml.push_str("        <key>value</key>\n        <dict>\n");
+            xml.push_str("            <key>NSStringFormatSpecTypeKey</key>\n");
+            xml.push_str("            <string>NSStringPluralRuleType</string>\n");
+            xml.push_str("            <key>NSStringFormatValueTypeKey</key>\n");
+            xml.push_str(&format!("            <string>{}</string>\n", value_type));
+
+            for category in PLURAL_CATEGORIES.iter() {
+                if let Some(Self::String(text)) = forms.get(*category) {
+                    xml.push_str(&format!(
+                        "            <key>{}</key>\n            <string>{}</string>\n",
+                        category,
+                        escape_xml(text)
+                    ));
+                }
+            }
+
+            xml.push_str("        </dict>\n    </dict>\n");
+        }
+
+        xml.push_str("</dict>\n</plist>\n");
+        Ok(xml)
+    }
+
+    /// Read the texts of an iOS `Localizable.strings` file and the plural texts of its
+    /// `Localizable.stringsdict` file if any.
+    ///
+    /// The keys are read as they are, like `button.save` or `Are you sure? [beta]`, unless the
+    /// file was written by `Value::to_ios_strings` or `Value::to_ios_stringsdict`, whose keys are
+    /// text paths like `home.title`.
+    ///
+    /// A plural text whose format has more text than its variable, like `%#@files@ selected`,
+    /// is read with the format in every form. The plural texts with several variables cannot be
+    /// read.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::Value;
+    ///
+    /// let texts = Value::from_ios_strings(
+    ///     String::from(
+    ///         r#"/* The title of the home page. */
+    ///         "home.title" = "Welcome,\n\"friend\"";
+    ///         // The name of the app.
+    ///         "app_name" = "My app";
+    ///         "Loading..." = "Loading...";"#,
+    ///     ),
+    ///     Some(String::from(
+    ///         r#"<?xml version="1.0" encoding="UTF-8"?>
+    ///         <plist version="1.0">
+    ///         <dict>
+    ///             <key>files</key>
+    ///             <dict>
+    ///                 <key>NSStringLocalizedFormatKey</key>
+    ///                 <string>%#@count@ selected</string>
+    ///                 <key>count</key>
+    ///                 <dict>
+    ///                     <key>NSStringFormatSpecTypeKey</key>
+    ///                     <string>NSStringPluralRuleType</string>
+    ///                     <key>NSStringFormatValueTypeKey</key>
+    ///                     <string>d</string>
+    ///                     <key>one</key>
+    ///                     <string>%d file</string>
+    ///                     <key>other</key>
+    ///                     <string>%d files</string>
+    ///                 </dict>
+    ///             </dict>
+    ///         </dict>
+    ///         </plist>"#,
+    ///     )),
+    /// )
+    /// .unwrap();
+    ///
+    /// let data = texts.get_object().unwrap();
+    /// assert_eq!(data["app_name"], Value::String(String::from("My app")));
+    /// assert_eq!(data["Loading..."], Value::String(String::from("Loading...")));
+    /// assert_eq!(
+    ///     data["home.title"],
+    ///     Value::String(String::from("Welcome,\n\"friend\""))
+    /// );
+    /// assert_eq!(
+    ///     data["files"].get_object().unwrap()["other"],
+    ///     Value::String(String::from("%d files selected"))
+    /// );
+    /// ```
+    pub fn from_ios_strings(strings: String, stringsdict: Option<String>) -> anyhow::Result<Self> {
+        let is_text_paths = strings
+            .trim_start_matches('\u{feff}')
+            .trim_start()
+            .starts_with(&format!("/* {} */", TEXT_PATHS_COMMENT));
+
+        let mut texts = Self::Object(HashMap::new());
+        for (key, text) in parse_strings(&strings)? {
+            texts
+                .insert_path(&get_path(&key, is_text_paths)?, Self::String(text))
+                .map_err(|e| {
+                    anyhow::Error::msg(format!("Cannot read the text `{}`: {}", key, e))
+                })?;
+        }
+
+        if let Some(stringsdict) = stringsdict {
+            let (is_text_paths, plurals) = parse_stringsdict(&stringsdict)?;
+            for (key, forms) in plurals {
+                texts
+                    .insert_path(&get_path(&key, is_text_paths)?, Self::Object(forms))
+                    .map_err(|e| {
+                        anyhow::Error::msg(format!("Cannot read the plural text `{}`: {}", key, e))
+                    })?;
+            }
+        }
+
+        Ok(texts)
+    }
+
+    /// Get the paths and texts of the strings and the paths and forms of the plural objects,
+    /// sorted by path.
+    #[allow(clippy::type_complexity)]
+    fn get_ios_entries(
+        &self,
+    ) -> anyhow::Result<(
+        Vec<(String, &String)>,
+        Vec<(String, &HashMap<String, Value>)>,
+    )> {
+        if !self.is_object() {
+            return Err(anyhow::Error::msg(format!(
+                "Cannot write `{}` as iOS texts, it is not an object.",
+                self
+            )));
+        }
+
+        let mut strings = Vec::new();
+        let mut plurals = Vec::new();
+        let mut pending = vec![(Vec::new(), self)];
+        while let Some((path, value)) = pending.pop() {
+            match value {
+                Self::String(text) => strings.push((path, text)),
+                Self::Array(values) => {
+                    for (index, value) in values.iter().enumerate() {
+                        let mut path = path.clone();
+                        path.push(PathSegment::Index(index));
+                        pending.push((path, value));
+                    }
+                }
+                Self::Object(forms) if !path.is_empty() && value.is_plural() => {
+                    plurals.push((path, forms))
+                }
+                Self::Object(data) => {
+                    for (key, value) in data.iter() {
+                        let mut path = path.clone();
+                        path.push(PathSegment::Key(key.clone()));
+                        pending.push((path, value));
+                    }
+                }
+            }
+        }
+
+        strings.sort_by(|(a, _), (b, _)| a.cmp(b));
+        plurals.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        Ok((
+            strings
+                .into_iter()
+                .map(|(path, text)| (PathSegment::join(&path), text))
+                .collect(),
+            plurals
+                .into_iter()
+                .map(|(path, forms)| (PathSegment::join(&path), forms))
+                .collect(),
+        ))
+    }
+}
+
+/// Get the path of a key, which is a top-level key unless the keys of the file are text paths.
+fn get_path(key: &str, is_text_paths: bool) -> anyhow::Result<Vec<PathSegment>> {
+    if is_text_paths {
+        PathSegment::parse(key)
+    } else {
+        Ok(vec![PathSegment::Key(String::from(key))])
+    }
+}
+
+/// Parse the `"key" = "value";` entries of a `.strings` file.
+fn parse_strings(text: &str) -> anyhow::Result<Vec<(String, String)>> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut entries = Vec::new();
+    let mut i = 0;
+    let mut line = 1;
+
+    let error = |line: usize, message: &str| {
+        anyhow::Error::msg(format!(
+            "Cannot parse the iOS texts, line {}: {}",
+            line, message
+        ))
+    };
+
+    loop {
+        skip_whitespace(&chars, &mut i, &mut line);
+        if i >= chars.len() {
+            break;
+        }
+
+        let key = parse_string(&chars, &mut i, &mut line)
+            .ok_or_else(|| error(line, "expected a quoted key"))?;
+
+        skip_whitespace(&chars, &mut i, &mut line);
+        if chars.get(i) != Some(&'=') {
+            return Err(error(line, "expected `=` after the key"));
+        }
+
+        i += 1;
+        skip_whitespace(&chars, &mut i, &mut line);
+        let value = parse_string(&chars, &mut i, &mut line)
+            .ok_or_else(|| error(line, "expected a quoted text"))?;
+
+        skip_whitespace(&chars, &mut i, &mut line);
+        if chars.get(i) != Some(&';') {
+            return Err(error(line, "expected `;` after the text"));
+        }
+
+        i += 1;
+        entries.push((key, value));
+    }
+
+    Ok(entries)
+}
+
+/// Skip the whitespaces and the `/* */` and `//` comments.
+fn skip_whitespace(chars: &[char], i: &mut usize, line: &mut usize) {
+    while *i < chars.len() {
+        match (chars[*i], chars.get(*i + 1)) {
+            ('\n', _) => {
+                *line += 1;
+                *i += 1;
+            }
+            (c, _) if c.is_whitespace() || c == '\u{feff}' => *i += 1,
+            ('/', Some('*')) => {
+                *i += 2;
+                while *i < chars.len() && !(chars[*i] == '*' && chars.get(*i + 1) == Some(&'/')) {
+                    if chars[*i] == '\n' {
+                        *line += 1;
+                    }
+
+                    *i += 1;
+                }
+
+                *i += 2;
+            }
+            ('/', Some('/')) => {
+                while *i < chars.len() && chars[*i] != '\n' {
+                    *i += 1;
+                }
+            }
+            _ => break,
+        }
+    }
+}
+
+/// Parse a quoted string without its escapes.
+fn parse_string(chars: &[char], i: &mut usize, line: &mut usize) -> Option<String> {
+    if chars.get(*i) != Some(&'"') {
+        return None;
+    }
+
+    let mut text = String::new();
+    *i += 1;
+    loop {
+        let c = *chars.get(*i)?;
+        *i += 1;
+        match c {
+            '"' => return Some(text),
+            '\\' => {
+                let c = *chars.get(*i)?;
+                *i += 1;
+                match c {
+                    'n' => text.push('\n'),
+                    't' => text.push('\t'),
+                    'r' => text.push('\r'),
+                    'u' | 'U' => {
+                        let code: String = chars.iter().skip(*i).take(4).collect();
+                        text.push(
+                            u32::from_str_radix(&code, 16)
+                                .ok()
+                                .and_then(char::from_u32)?,
+                        );
+                        *i += 4;
+                    }
+                    c => text.push(c),
+                }
+            }
+            '\n' => {
+                *line += 1;
+                text.push(c);
+            }
+            c => text.push(c),
+        }
+    }
+}
+
+/// Parse the plural texts of a `.stringsdict` file, and check if its keys are text paths.
+#[allow(clippy::type_complexity)]
+fn parse_stringsdict(text: &str) -> anyhow::Result<(bool, Vec<(String, HashMap<String, Value>)>)> {
+    let options = ParsingOptions {
+        allow_dtd: true,
+        ..ParsingOptions::default()
+    };
+
+    let document = Document::parse_with_options(text, options)
+        .map_err(|e| anyhow::Error::msg(format!("Cannot parse the iOS plural texts: {}", e)))?;
+
+    let is_text_paths = document
+        .root_element()
+        .children()
+        .any(|node| node.is_comment() && node.text().map(str::trim) == Some(TEXT_PATHS_COMMENT));
+
+    let dict = document
+        .root_element()
+        .children()
+        .find(|node| node.is_element() && node.tag_name().name() == "dict")
+        .ok_or_else(|| anyhow::Error::msg("The iOS plural texts do not have a `dict` element."))?;
+
+    let mut plurals = Vec::new();
+    for (key, node) in get_entries(dict) {
+        let entries = get_entries(node);
+        let format = entries
+            .iter()
+            .find(|(name, _)| name == "NSStringLocalizedFormatKey")
+            .map(|(_, node)| get_text(*node))
+            .ok_or_else(|| {
+                anyhow::Error::msg(format!(
+                    "The plural text `{}` does not have a `NSStringLocalizedFormatKey`.",
+                    key
+                ))
+            })?;
+
+        let variables = get_variables(&format);
+        if variables.len() != 1 {
+            return Err(anyhow::Error::msg(format!(
+                "Cannot read the plural text `{}`, it must have one variable but it has {}.",
+                key,
+                variables.len()
+            )));
+        }
+
+        let variable = &variables[0];
+        let mut forms = HashMap::new();
+        if let Some((_, node)) = entries.iter().find(|(name, _)| name == variable) {
+            for (category, node) in get_entries(*node) {
+                if PLURAL_CATEGORIES.contains(&category.as_str()) {
+                    let form = format.replace(&format!("%#@{}@", variable), &get_text(node));
+                    forms.insert(category, Value::String(form));
+                }
+            }
+        }
+
+        if !forms.contains_key("other") {
+            return Err(anyhow::Error::msg(format!(
+                "The plural text `{}` does not have the `other` form.",
+                key
+            )));
+        }
+
+        plurals.push((key, forms));
+    }
+
+    Ok((is_text_paths, plurals))
+}
+
+/// Get the `<key>` and value pairs of a `<dict>` element.
+fn get_entries<'a, 'input>(dict: Node<'a, 'input>) -> Vec<(String, Node<'a, 'input>)> {
+    let mut entries = Vec::new();
+    let mut key = None;
+    for node in dict.children().filter(|node| node.is_element()) {
+        match (node.tag_name().name(), key.take()) {
+            ("key", _) => key = Some(get_text(node)),
+            (_, Some(key)) => entries.push((key, node)),
+            _ => {}
+        }
+    }
+
+    entries
+}
+
+fn get_text(node: Node) -> String {
+    node.text().map(String::from).unwrap_or_default()
+}
+
+/// Get the names of the variables of a plural format, like `count` in `%#@count@ selected`.
+fn get_variables(format: &str) -> Vec<String> {
+    let mut variables = Vec::new();
+    let mut rest = format;
+    while let Some(start) = rest.find("%#@") {
+        rest = &rest[start + 3..];
+        match rest.find('@') {
+            Some(end) => {
+                variables.push(String::from(&rest[..end]));
+                rest = &rest[end + 1..];
+            }
+            None => break,
+        }
+    }
+
+    variables
+}
+
+/// Get the type of the first printf placeholder of a text, like `lld` in `%lld items`.
+fn get_value_type(text: &str) -> String {
+    let mut rest = text;
+    while let Some(start) = rest.find('%') {
+        rest = &rest[start + 1..];
+        if rest.starts_with('%') {
+            rest = &rest[1..];
+            continue;
+        }
+
+        // Skip the position, like `1$`, and the flags and width.
+        let spec = rest.trim_start_matches(|c: char| c.is_ascii_digit() || "$-+ #.".contains(c));
+        let length = spec.len()
+            - spec
+                .trim_start_matches(['h', 'l', 'q', 'z', 't', 'j'])
+                .len();
+        if let Some(conversion) = spec[length..].chars().next() {
+            if conversion.is_ascii_alphabetic() || conversion == '@' {
+                return format!("{}{}", &spec[..length], conversion);
+            }
+        }
+    }
+
+    String::from("d")
+}
+
+/// Escape a text for the `.strings` files.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            '\r' => escaped.push_str("\\r"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
@@ -0,0 +1,130 @@
+use std::collections::BTreeSet;
+
+/// Get the names of the placeholders of a text, like `name` in `Hi {name}` or `count` in
+/// `{count, plural, one {# item} other {# items}}`, sorted and without duplicates.
+///
+/// The texts inside the ICU plural and select options are read too, and the ICU quoted texts
+/// like `'{'` are ignored.
+pub(crate) fn get_placeholders(text: &str) -> Vec<String> {
+    let mut names = BTreeSet::new();
+    collect_placeholders(&text.chars().collect::<Vec<char>>(), &mut names);
+
+    names.into_iter().collect()
+}
+
+fn collect_placeholders(chars: &[char], names: &mut BTreeSet<String>) {
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            // Skip the quoted texts, where `''` is a single apostrophe.
+            '\'' if matches!(chars.get(i + 1), Some('{') | Some('}')) => {
+                i += 2;
+                while i < chars.len() && chars[i] != '\'' {
+                    i += 1;
+                }
+            }
+            '{' => {
+                let end = match find_closing_brace(chars, i) {
+                    Some(end) => end,
+                    // The brace is not closed, so it is not a placeholder.
+                    None => return,
+                };
+
+                collect_argument(&chars[i + 1..end], names);
+                i = end;
+            }
+            _ => {}
+        }
+
+        i += 1;
+    }
+}
+
+/// Read the content of a `{...}` group, which is an argument like `name` or
+/// `count, plural, ...`, or a nested message like the options of a plural.
+fn collect_argument(chars: &[char], names: &mut BTreeSet<String>) {
+    let comma = chars.iter().position(|c| *c == ',');
+    let name: String = chars[..comma.unwrap_or(chars.len())]
+        .iter()
+        .collect::<String>()
+        .trim()
+        .to_string();
+
+    if !is_argument_name(&name) {
+        collect_placeholders(chars, names);
+        return;
+    }
+
+    names.insert(name);
+
+    // Read the messages of the ICU plural and select arguments, like
+    // `plural, one {# item} other {# items}`. The other arguments, like `number, integer`, do
+    // not have messages.
+    let rest = match comma {
+        Some(comma) => &chars[comma + 1..],
+        None => return,
+    };
+
+    let (kind, options) = match rest.iter().position(|c| *c == ',') {
+        Some(comma) => (rest[..comma].iter().collect::<String>(), &rest[comma + 1..]),
+        None => return,
+    };
+
+    if matches!(kind.trim(), "plural" | "selectordinal" | "select") {
+        collect_options(options, names);
+    }
+}
+
+/// Read the `selector {message}` pairs of an ICU plural or select argument, like
+/// `one {# item} other {# items}`, with the placeholders of the messages.
+fn collect_options(chars: &[char], names: &mut BTreeSet<String>) {
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i] == '{' {
+            let end = match find_closing_brace(chars, i) {
+                Some(end) => end,
+                None => return,
+            };
+
+            collect_placeholders(&chars[i + 1..end], names);
+            i = end;
+        }
+
+        // The selectors like `one`, `=0` or `offset:1` are not placeholders.
+        i += 1;
+    }
+}
+
+pub(crate) fn find_closing_brace(chars: &[char], start: usize) -> Option<usize> {
+    let mut depth = 0;
+    let mut i = start;
+    while i < chars.len() {
+        match chars[i] {
+            '\'' if matches!(chars.get(i + 1), Some('{') | Some('}')) => {
+                i += 2;
+                while i < chars.len() && chars[i] != '\'' {
+                    i += 1;
+                }
+            }
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+
+        i += 1;
+    }
+
+    None
+}
+
+pub(crate) fn is_argument_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '-' || c == '.')
+}
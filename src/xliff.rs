@@ -0,0 +1,699 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::Path,
+};
+
+use roxmltree::{Document, Node};
+
+use crate::{source::write_atomic, LanguageTexts, Value};
+
+/// The versions of the XLIFF format.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum XliffVersion {
+    V1_2,
+    V2_0,
+}
+
+impl XliffVersion {
+    /// Get the version as it is written in the `version` attribute.
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Self::V1_2 => "1.2",
+            Self::V2_0 => "2.0",
+        }
+    }
+
+    /// Get the state of a unit in this version from the state of the other version, like
+    /// `needs-translation` in XLIFF 1.2 and `initial` in XLIFF 2.0.
+    fn convert_state(&self, state: &str) -> String {
+        let state = match (self, state) {
+            (Self::V2_0, "new") | (Self::V2_0, "needs-translation") => "initial",
+            (Self::V2_0, "signed-off")
+            | (Self::V2_0, "needs-review-translation")
+            | (Self::V2_0, "needs-review-adaptation")
+            | (Self::V2_0, "needs-review-l10n") => "reviewed",
+            (Self::V1_2, "initial") => "new",
+            (Self::V1_2, "reviewed") => "signed-off",
+            (_, state) => state,
+        };
+
+        String::from(state)
+    }
+}
+
+/// A text of an XLIFF file, with the text path as its ID.
+#[derive(Clone, Debug, PartialEq)]
+pub struct XliffUnit {
+    id: String,
+    source: String,
+    target: Option<String>,
+    state: Option<String>,
+    notes: Vec<String>,
+}
+
+impl XliffUnit {
+    /// Create a new unit for a text path, like `pages.home.title` or `messages[0]`.
+    pub fn new(id: &str, source: &str) -> Self {
+        Self {
+            id: String::from(id),
+            source: String::from(source),
+            target: None,
+            state: None,
+            notes: Vec::new(),
+        }
+    }
+
+    /// Get the text path of the unit.
+    pub fn get_id(&self) -> String {
+        self.id.clone()
+    }
+
+    /// Get the text of the source language.
+    pub fn get_source(&self) -> String {
+        self.source.clone()
+    }
+
+    /// Get the text of the target language, if it is translated.
+    pub fn get_target(&self) -> Option<String> {
+        self.target.clone()
+    }
+
+    /// Change the text of the target language.
+    pub fn set_target(&mut self, target: &str) {
+        self.target = Some(String::from(target));
+    }
+
+    /// Get the state of the translation, like `translated` or `final`.
+    pub fn get_state(&self) -> Option<String> {
+        self.state.clone()
+    }
+
+    /// Change the state of the translation, like `translated` or `final`.
+    pub fn set_state(&mut self, state: &str) {
+        self.state = Some(String::from(state));
+    }
+
+    /// Get the notes of the unit for the translators.
+    pub fn get_notes(&self) -> Vec<String> {
+        self.notes.clone()
+    }
+
+    /// Add a note for the translators.
+    pub fn add_note(&mut self, note: &str) {
+        self.notes.push(String::from(note));
+    }
+}
+
+/// An XLIFF 1.2 or 2.0 file with the texts of a source language and their translations to a
+/// target language, to send the texts to translators.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Xliff {
+    version: XliffVersion,
+    source_language: String,
+    target_language: String,
+    units: Vec<XliffUnit>,
+}
+
+impl Xliff {
+    /// Create an XLIFF file with a unit for every string of the source language, using the
+    /// strings of the target language that are not empty as translations.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::{LanguageTexts, Value, Xliff, XliffVersion};
+    ///
+    /// let mut data = HashMap::new();
+    /// data.insert(String::from("hi"), Value::String(String::from("Hi")));
+    /// data.insert(String::from("bye"), Value::String(String::from("Bye & see you")));
+    /// let texts_en = LanguageTexts::new(String::from("en"), Value::Object(data)).unwrap();
+    ///
+    /// let mut data = HashMap::new();
+    /// data.insert(String::from("hi"), Value::String(String::from("Hola")));
+    /// let texts_es = LanguageTexts::new(String::from("es"), Value::Object(data)).unwrap();
+    ///
+    /// let xliff = Xliff::from_languages(&texts_en, &texts_es, XliffVersion::V1_2);
+    /// assert_eq!(
+    ///     xliff.to_xml_string(),
+    ///     r#"<?xml version="1.0" encoding="UTF-8"?>
+    /// <xliff version="1.2" xmlns="urn:oasis:names:tc:xliff:document:1.2">
+    ///   <file original="languages-rs" datatype="plaintext" source-language="en" target-language="es">
+    ///     <body>
+    ///       <trans-unit id="bye">
+    ///         <source>Bye &amp; see you</source>
+    ///       </trans-unit>
+    ///       <trans-unit id="hi">
+    ///         <source>Hi</source>
+    ///         <target state="translated">Hola</target>
+    ///       </trans-unit>
+    ///     </body>
+    ///   </file>
+    /// </xliff>
+    /// "#
+    /// );
+    /// ```
+    pub fn from_languages(
+        source: &LanguageTexts,
+        target: &LanguageTexts,
+        version: XliffVersion,
+    ) -> Self {
+        let targets: HashMap<String, String> = target.get_texts().flatten().into_iter().collect();
+
+        let units = source
+            .get_texts()
+            .flatten()
+            .into_iter()
+            .map(|(path, text)| {
+                let mut unit = XliffUnit::new(&path, &text);
+                if let Some(target) = targets
+                    .get(&path)
+                    .filter(|target| !target.trim().is_empty())
+                {
+                    unit.set_target(target);
+                    unit.set_state("translated");
+                }
+
+                unit
+            })
+            .collect();
+
+        Self {
+            version,
+            source_language: source.get_language(),
+            target_language: target.get_language(),
+            units,
+        }
+    }
+
+    /// Read an XLIFF 1.2 or 2.0 file from a string, keeping the notes and the states of the
+    /// units.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::{Xliff, XliffVersion};
+    ///
+    /// let xliff = Xliff::from_xml_string(
+    ///     r#"<?xml version="1.0" encoding="UTF-8"?>
+    ///     <xliff version="2.0" xmlns="urn:oasis:names:tc:xliff:document:2.0" srcLang="en" trgLang="es">
+    ///       <file id="f1">
+    ///         <unit id="home.title">
+    ///           <notes><note>The title of the home page.</note></notes>
+    ///           <segment state="final">
+    ///             <source>Home</source>
+    ///             <target>Inicio</target>
+    ///           </segment>
+    ///         </unit>
+    ///         <unit id="u2" name="messages[0]">
+    ///           <segment><source>Message</source></segment>
+    ///         </unit>
+    ///       </file>
+    ///     </xliff>"#,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(xliff.get_version(), XliffVersion::V2_0);
+    /// assert_eq!(xliff.get_target_language(), "es");
+    ///
+    /// let units = xliff.get_units();
+    /// assert_eq!(units[0].get_id(), "home.title");
+    /// assert_eq!(units[0].get_target(), Some(String::from("Inicio")));
+    /// assert_eq!(units[0].get_state(), Some(String::from("final")));
+    /// assert_eq!(units[0].get_notes(), vec![String::from("The title of the home page.")]);
+    /// assert_eq!(units[1].get_id(), "messages[0]");
+    /// assert_eq!(units[1].get_target(), None);
+    /// ```
+    pub fn from_xml_string(text: &str) -> anyhow::Result<Self> {
+        let document = Document::parse(text)
+            .map_err(|e| anyhow::Error::msg(format!("Cannot parse the XLIFF file: {}", e)))?;
+
+        let root = document.root_element();
+        if root.tag_name().name() != "xliff" {
+            return Err(anyhow::Error::msg(format!(
+                "The root element must be `xliff`, but it is `{}`.",
+                root.tag_name().name()
+            )));
+        }
+
+        match root.attribute("version") {
+            Some("1.2") => Ok(parse_v1_2(root)),
+            Some(version) if version.starts_with("2.") => Ok(parse_v2_0(root)),
+            Some(version) => Err(anyhow::Error::msg(format!(
+                "The XLIFF version `{}` is not supported.",
+                version
+            ))),
+            None => Err(anyhow::Error::msg(
+                "The XLIFF file does not have a version.",
+            )),
+        }
+    }
+
+    /// Write the XLIFF file as a string.
+    ///
+    /// The IDs of XLIFF 2.0 cannot have characters like `[`, so the units of those text paths
+    /// get IDs like `u1`, different from the other IDs, and their text paths are written in the
+    /// `name` attribute.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::{LanguageTexts, Value, Xliff, XliffVersion};
+    ///
+    /// let mut data = HashMap::new();
+    /// data.insert(String::from("u1"), Value::String(String::from("Hi")));
+    /// data.insert(
+    ///     String::from("list"),
+    ///     Value::Array(vec![Value::String(String::from("Bye"))]),
+    /// );
+    ///
+    /// let texts = LanguageTexts::new(String::from("en"), Value::Object(data)).unwrap();
+    /// let xliff = Xliff::from_languages(&texts, &texts, XliffVersion::V2_0);
+    ///
+    /// let xml = xliff.to_xml_string();
+    /// assert!(xml.contains(r#"<unit id="u1">"#));
+    /// assert!(xml.contains(r#"<unit id="u2" name="list[0]">"#));
+    ///
+    /// assert_eq!(Xliff::from_xml_string(&xml).unwrap(), xliff);
+    /// ```
+    pub fn to_xml_string(&self) -> String {
+        match self.version {
+            XliffVersion::V1_2 => self.write_v1_2(),
+            XliffVersion::V2_0 => self.write_v2_0(),
+        }
+    }
+
+    /// Read an XLIFF file.
+    pub fn read<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)
+            .map_err(|e| anyhow::Error::msg(format!("Cannot read `{}`: {}", path.display(), e)))?;
+
+        Self::from_xml_string(&text).map_err(|e| {
+            anyhow::Error::msg(format!("Cannot parse `{}` file: {}", path.display(), e))
+        })
+    }
+
+    /// Write the XLIFF file atomically.
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> anyhow::Result<()> {
+        write_atomic(path.as_ref(), self.to_xml_string())
+    }
+
+    /// Get the version of the file.
+    pub fn get_version(&self) -> XliffVersion {
+        self.version
+    }
+
+    /// Change the version of the file, converting the states of the units, like `initial` in
+    /// XLIFF 2.0 to `new` in XLIFF 1.2.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use languages_rs::{LanguageTexts, Value, Xliff, XliffVersion};
+    ///
+    /// let mut data = HashMap::new();
+    /// data.insert(String::from("hi"), Value::String(String::from("Hi")));
+    /// let texts_en = LanguageTexts::new(String::from("en"), Value::Object(data)).unwrap();
+    /// let texts_es = LanguageTexts::new(String::from("es"), Value::Object(HashMap::new())).unwrap();
+    ///
+    /// let mut xliff = Xliff::from_languages(&texts_en, &texts_es, XliffVersion::V1_2);
+    /// xliff.get_units_mut()[0].set_state("needs-translation");
+    /// xliff.get_units_mut()[0].add_note("A greeting.");
+    ///
+    /// xliff.set_version(XliffVersion::V2_0);
+    /// assert_eq!(
+    ///     xliff.to_xml_string(),
+    ///     r#"<?xml version="1.0" encoding="UTF-8"?>
+    /// <xliff version="2.0" xmlns="urn:oasis:names:tc:xliff:document:2.0" srcLang="en" trgLang="es">
+    ///   <file id="f1" original="languages-rs">
+    ///     <unit id="hi">
+    ///       <notes>
+    ///         <note>A greeting.</note>
+    ///       </notes>
+    ///       <segment state="initial">
+    ///         <source>Hi</source>
+    ///       </segment>
+    ///     </unit>
+    ///   </file>
+    /// </xliff>
+    /// "#
+    /// );
+    /// ```
+    pub fn set_version(&mut self, version: XliffVersion) {
+        if self.version != version {
+            for unit in self.units.iter_mut() {
+                unit.state = unit
+                    .state
+                    .as_ref()
+                    .map(|state| version.convert_state(state));
+            }
+
+            self.version = version;
+        }
+    }
+
+    /// Get the source language.
+    pub fn get_source_language(&self) -> String {
+        self.source_language.clone()
+    }
+
+    /// Get the target language.
+    pub fn get_target_language(&self) -> String {
+        self.target_language.clone()
+    }
+
+    /// Get the units of the file.
+    pub fn get_units(&self) -> Vec<XliffUnit> {
+        self.units.clone()
+    }
+
+    /// Get the units of the file to change their targets, states or notes.
+    pub fn get_units_mut(&mut self) -> &mut Vec<XliffUnit> {
+        &mut self.units
+    }
+
+    /// Get the texts of the source language.
+    pub fn get_source_texts(&self) -> anyhow::Result<LanguageTexts> {
+        let texts = Value::unflatten(
+            self.units
+                .iter()
+                .map(|unit| (unit.id.clone(), unit.source.clone()))
+                .collect(),
+        )?;
+
+        LanguageTexts::new(self.source_language.clone(), texts)
+    }
+
+    /// Get the translated texts of the target language, without the units that do not have a
+    /// target, to write them as a `JSON` or `TOML` file.
+    ///
+    /// # Example
+    /// ```rust
+    /// use languages_rs::{Value, Xliff};
+    ///
+    /// let xliff = Xliff::from_xml_string(
+    ///     r#"<xliff version="1.2">
+    ///       <file source-language="en" target-language="es">
+    ///         <body>
+    ///           <trans-unit id="pages.home.title">
+    ///             <source>Home</source>
+    ///             <target state="translated">Inicio</target>
+    ///             <note>The title of the home page.</note>
+    ///           </trans-unit>
+    ///           <trans-unit id="messages[0]">
+    ///             <source>Message</source>
+    ///             <target state="needs-review-translation">Mensaje</target>
+    ///           </trans-unit>
+    ///           <trans-unit id="bye">
+    ///             <source>Bye</source>
+    ///           </trans-unit>
+    ///         </body>
+    ///       </file>
+    ///     </xliff>"#,
+    /// )
+    /// .unwrap();
+    ///
+    /// let texts_es = xliff.get_target_texts().unwrap();
+    /// assert_eq!(texts_es.get_language(), "es");
+    /// assert_eq!(
+    ///     texts_es.try_get_path("pages.home.title"),
+    ///     Some(Value::String(String::from("Inicio")))
+    /// );
+    /// assert_eq!(
+    ///     texts_es.try_get_path("messages[0]"),
+    ///     Some(Value::String(String::from("Mensaje")))
+    /// );
+    /// assert_eq!(texts_es.try_get_path("bye"), None);
+    /// ```
+    pub fn get_target_texts(&self) -> anyhow::Result<LanguageTexts> {
+        let texts = Value::unflatten(
+            self.units
+                .iter()
+                .filter_map(|unit| {
+                    unit.target
+                        .as_ref()
+                        .map(|target| (unit.id.clone(), target.clone()))
+                })
+                .collect(),
+        )?;
+
+        LanguageTexts::new(self.target_language.clone(), texts)
+    }
+
+    fn write_v1_2(&self) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str("<xliff version=\"1.2\" xmlns=\"urn:oasis:names:tc:xliff:document:1.2\">\n");
+        xml.push_str(&format!(
+            "  <file original=\"languages-rs\" datatype=\"plaintext\" source-language=\"{}\" target-language=\"{}\">\n",
+            escape(&self.source_language),
+            escape(&self.target_language)
+        ));
+        xml.push_str("    <body>\n");
+
+        for unit in self.units.iter() {
+            xml.push_str(&format!("      <trans-unit id=\"{}\">\n", escape(&unit.id)));
+            xml.push_str(&format!(
+                "        <source>{}</source>\n",
+                escape(&unit.source)
+            ));
+
+            match (&unit.target, &unit.state) {
+                (Some(target), Some(state)) => xml.push_str(&format!(
+                    "        <target state=\"{}\">{}</target>\n",
+                    escape(state),
+                    escape(target)
+                )),
+                (Some(target), None) => {
+                    xml.push_str(&format!("        <target>{}</target>\n", escape(target)))
+                }
+                (None, Some(state)) => {
+                    xml.push_str(&format!("        <target state=\"{}\"/>\n", escape(state)))
+                }
+                (None, None) => {}
+            }
+
+            for note in unit.notes.iter() {
+                xml.push_str(&format!("        <note>{}</note>\n", escape(note)));
+            }
+
+            xml.push_str("      </trans-unit>\n");
+        }
+
+        xml.push_str("    </body>\n  </file>\n</xliff>\n");
+        xml
+    }
+
+    fn write_v2_0(&self) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str(&format!(
+            "<xliff version=\"2.0\" xmlns=\"urn:oasis:names:tc:xliff:document:2.0\" srcLang=\"{}\" trgLang=\"{}\">\n",
+            escape(&self.source_language),
+            escape(&self.target_language)
+        ));
+        xml.push_str("  <file id=\"f1\" original=\"languages-rs\">\n");
+
+        // The IDs of XLIFF 2.0 cannot have characters like `[`, so the text paths with them are
+        // written in the `name` attribute with an ID that no other unit uses.
+        let used_ids: HashSet<&str> = self
+            .units
+            .iter()
+            .map(|unit| unit.id.as_str())
+            .filter(|id| is_nmtoken(id))
+            .collect();
+
+        let mut next_id = 0;
+        for unit in self.units.iter() {
+            if is_nmtoken(&unit.id) {
+                xml.push_str(&format!("    <unit id=\"{}\">\n", unit.id));
+            } else {
+                let id = loop {
+                    next_id += 1;
+                    let id = format!("u{}", next_id);
+                    if !used_ids.contains(id.as_str()) {
+                        break id;
+                    }
+                };
+
+                xml.push_str(&format!(
+                    "    <unit id=\"{}\" name=\"{}\">\n",
+                    id,
+                    escape(&unit.id)
+                ));
+            }
+
+            if !unit.notes.is_empty() {
+                xml.push_str("      <notes>\n");
+                for note in unit.notes.iter() {
+                    xml.push_str(&format!("        <note>{}</note>\n", escape(note)));
+                }
+
+                xml.push_str("      </notes>\n");
+            }
+
+            match &unit.state {
+                Some(state) => {
+                    xml.push_str(&format!("      <segment state=\"{}\">\n", escape(state)))
+                }
+                None => xml.push_str("      <segment>\n"),
+            }
+
+            xml.push_str(&format!(
+                "        <source>{}</source>\n",
+                escape(&unit.source)
+            ));
+
+            if let Some(target) = &unit.target {
+                xml.push_str(&format!("        <target>{}</target>\n", escape(target)));
+            }
+
+            xml.push_str("      </segment>\n    </unit>\n");
+        }
+
+        xml.push_str("  </file>\n</xliff>\n");
+        xml
+    }
+}
+
+fn parse_v1_2(root: Node) -> Xliff {
+    let mut xliff = Xliff {
+        version: XliffVersion::V1_2,
+        source_language: String::new(),
+        target_language: String::new(),
+        units: Vec::new(),
+    };
+
+    for file in get_children(root, "file") {
+        if xliff.source_language.is_empty() {
+            xliff.source_language = String::from(file.attribute("source-language").unwrap_or(""));
+            xliff.target_language = String::from(file.attribute("target-language").unwrap_or(""));
+        }
+
+        for unit in file
+            .descendants()
+            .filter(|node| node.tag_name().name() == "trans-unit")
+        {
+            let id = match unit.attribute("resname").or_else(|| unit.attribute("id")) {
+                Some(id) => id,
+                None => continue,
+            };
+
+            let source = get_children(unit, "source")
+                .next()
+                .map(get_text)
+                .unwrap_or_default();
+
+            let mut xliff_unit = XliffUnit::new(id, &source);
+            if let Some(target) = get_children(unit, "target").next() {
+                let text = get_text(target);
+                if !text.is_empty() {
+                    xliff_unit.target = Some(text);
+                }
+
+                xliff_unit.state = target.attribute("state").map(String::from);
+            }
+
+            xliff_unit.notes = get_children(unit, "note").map(get_text).collect();
+            xliff.units.push(xliff_unit);
+        }
+    }
+
+    xliff
+}
+
+fn parse_v2_0(root: Node) -> Xliff {
+    let mut xliff = Xliff {
+        version: XliffVersion::V2_0,
+        source_language: String::from(root.attribute("srcLang").unwrap_or("")),
+        target_language: String::from(root.attribute("trgLang").unwrap_or("")),
+        units: Vec::new(),
+    };
+
+    for unit in root
+        .descendants()
+        .filter(|node| node.tag_name().name() == "unit")
+    {
+        let id = match unit.attribute("name").or_else(|| unit.attribute("id")) {
+            Some(id) => id,
+            None => continue,
+        };
+
+        // The texts of a unit can be split in several segments.
+        let mut source = String::new();
+        let mut target: Option<String> = None;
+        let mut state = None;
+        for segment in unit.children().filter(|node| {
+            node.is_element() && matches!(node.tag_name().name(), "segment" | "ignorable")
+        }) {
+            if let Some(node) = get_children(segment, "source").next() {
+                source.push_str(&get_text(node));
+            }
+
+            if let Some(node) = get_children(segment, "target").next() {
+                target
+                    .get_or_insert_with(String::new)
+                    .push_str(&get_text(node));
+            }
+
+            if state.is_none() {
+                state = segment.attribute("state").map(String::from);
+            }
+        }
+
+        let mut xliff_unit = XliffUnit::new(id, &source);
+        xliff_unit.target = target.filter(|target| !target.is_empty());
+        xliff_unit.state = state;
+        xliff_unit.notes = get_children(unit, "notes")
+            .flat_map(|notes| get_children(notes, "note"))
+            .map(get_text)
+            .collect();
+
+        xliff.units.push(xliff_unit);
+    }
+
+    xliff
+}
+
+/// Get the child elements of a node with a name, ignoring the namespace.
+fn get_children<'a, 'input: 'a>(
+    node: Node<'a, 'input>,
+    name: &'a str,
+) -> impl Iterator<Item = Node<'a, 'input>> + 'a {
+    node.children()
+        .filter(move |child| child.is_element() && child.tag_name().name() == name)
+}
+
+/// Get the text of an element, including the text of its inline elements.
+fn get_text(node: Node) -> String {
+    node.descendants()
+        .filter(|node| node.is_text())
+        .filter_map(|node| node.text())
+        .collect()
+}
+
+/// Check if a text can be used as an XLIFF 2.0 ID.
+fn is_nmtoken(text: &str) -> bool {
+    !text.is_empty()
+        && text
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '.' | '-' | '_' | ':'))
+}
+
+/// Escape the special characters of XML texts and attributes.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}